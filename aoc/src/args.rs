@@ -0,0 +1,235 @@
+use std::path::PathBuf;
+
+use common::input::Source;
+use common::Part;
+
+pub const USAGE: &str = "Usage:
+  aoc run [<day>|all] [--part 1|2] [--input <path>|-]
+  aoc bench [<day>|all] [--iterations <n>] [--baseline <path>] [--save]
+  aoc verify [<day>|all] [--answers <path>]
+
+Inputs are read from dayNN/input.txt under $AOC_INPUT_DIR, or under the
+current directory if it isn't set. answers.toml and bench_baseline.json
+default to the current directory too.";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run {
+        day: Option<u8>,
+        part: Option<Part>,
+        input: Option<Source>,
+    },
+    Bench {
+        day: Option<u8>,
+        iterations: usize,
+        baseline: Option<PathBuf>,
+        save: bool,
+    },
+    Verify {
+        day: Option<u8>,
+        answers: Option<PathBuf>,
+    },
+}
+
+pub fn parse<I>(args: I) -> Result<Command, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter();
+
+    match args.next().as_deref() {
+        Some("run") => parse_run(args),
+        Some("bench") => parse_bench(args),
+        Some("verify") => parse_verify(args),
+        Some(other) => Err(format!("Unknown command: {}", other)),
+        None => Err("No command given".to_string()),
+    }
+}
+
+fn parse_run(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut day = None;
+    let mut part = None;
+    let mut input = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => {
+                let value = args.next().ok_or("Missing value for --part")?;
+                part = match value.as_str() {
+                    "1" => Some(Part::One),
+                    "2" => Some(Part::Two),
+                    _ => return Err(format!("Invalid part: {}", value)),
+                };
+            }
+            "--input" | "-i" => {
+                let value = args.next().ok_or("Missing value for --input")?;
+                input = Some(match value.as_str() {
+                    "-" => Source::Stdin,
+                    path => Source::Path(PathBuf::from(path)),
+                });
+            }
+            "all" => day = None,
+            value => day = Some(parse_day(value)?),
+        }
+    }
+
+    if input.is_some() && day.is_none() {
+        return Err("--input can only be used when running a single day".to_string());
+    }
+
+    Ok(Command::Run { day, part, input })
+}
+
+fn parse_bench(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut day = None;
+    let mut iterations = crate::bench::DEFAULT_ITERATIONS;
+    let mut baseline = None;
+    let mut save = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--iterations" | "-n" => {
+                let value = args.next().ok_or("Missing value for --iterations")?;
+                iterations = value
+                    .parse()
+                    .ok()
+                    .filter(|iterations| *iterations > 0)
+                    .ok_or_else(|| format!("Invalid iterations: {}", value))?;
+            }
+            "--baseline" | "-b" => {
+                let value = args.next().ok_or("Missing value for --baseline")?;
+                baseline = Some(PathBuf::from(value));
+            }
+            "--save" => save = true,
+            "all" => day = None,
+            value => day = Some(parse_day(value)?),
+        }
+    }
+
+    Ok(Command::Bench {
+        day,
+        iterations,
+        baseline,
+        save,
+    })
+}
+
+fn parse_verify(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut day = None;
+    let mut answers = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--answers" | "-a" => {
+                let value = args.next().ok_or("Missing value for --answers")?;
+                answers = Some(PathBuf::from(value));
+            }
+            "all" => day = None,
+            value => day = Some(parse_day(value)?),
+        }
+    }
+
+    Ok(Command::Verify { day, answers })
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    value
+        .trim_start_matches("day")
+        .parse()
+        .ok()
+        .filter(|day| (1..=25).contains(day))
+        .ok_or_else(|| format!("Invalid day: {}", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_run_all() {
+        assert_eq!(
+            parse(args("run")),
+            Ok(Command::Run {
+                day: None,
+                part: None,
+                input: None
+            })
+        );
+        assert_eq!(parse(args("run all")), parse(args("run")));
+    }
+
+    #[test]
+    fn test_parse_run_single_day() {
+        assert_eq!(
+            parse(args("run day05 --part 2 --input other.txt")),
+            Ok(Command::Run {
+                day: Some(5),
+                part: Some(Part::Two),
+                input: Some(Source::Path(PathBuf::from("other.txt")))
+            })
+        );
+        assert_eq!(
+            parse(args("run 5 --input -")),
+            Ok(Command::Run {
+                day: Some(5),
+                part: None,
+                input: Some(Source::Stdin)
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        assert_eq!(
+            parse(args("bench")),
+            Ok(Command::Bench {
+                day: None,
+                iterations: crate::bench::DEFAULT_ITERATIONS,
+                baseline: None,
+                save: false
+            })
+        );
+        assert_eq!(
+            parse(args("bench 6 -n 20 --baseline base.json --save")),
+            Ok(Command::Bench {
+                day: Some(6),
+                iterations: 20,
+                baseline: Some(PathBuf::from("base.json")),
+                save: true
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_verify() {
+        assert_eq!(
+            parse(args("verify")),
+            Ok(Command::Verify {
+                day: None,
+                answers: None
+            })
+        );
+        assert_eq!(
+            parse(args("verify day10 --answers other.toml")),
+            Ok(Command::Verify {
+                day: Some(10),
+                answers: Some(PathBuf::from("other.toml"))
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_arguments() {
+        assert!(parse(args("")).is_err());
+        assert!(parse(args("jump 5")).is_err());
+        assert!(parse(args("run 26")).is_err());
+        assert!(parse(args("run 5 --part 3")).is_err());
+        assert!(parse(args("run --input other.txt")).is_err());
+        assert!(parse(args("bench 6 --iterations 0")).is_err());
+        assert!(parse(args("bench 6 --baseline")).is_err());
+    }
+}
@@ -0,0 +1,255 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use common::{input, DynSolution, Part};
+use serde::{Deserialize, Serialize};
+
+use crate::days;
+
+pub const DEFAULT_ITERATIONS: usize = 100;
+
+// Slow parts stop early once they have been running for this long, so that
+// benchmarking every day doesn't take hours
+const TIME_BUDGET: Duration = Duration::from_secs(2);
+
+const REGRESSION_THRESHOLD: f64 = 0.1;
+
+// Day name -> measurement label -> stats
+pub type Baseline = BTreeMap<String, BTreeMap<String, Stats>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub samples: usize,
+    pub mean_ns: u64,
+    pub median_ns: u64,
+    pub p99_ns: u64,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        samples.sort_unstable();
+        let count = samples.len().max(1);
+        let nanos =
+            |duration: Option<&Duration>| duration.map_or(0, |duration| duration.as_nanos() as u64);
+        let total: Duration = samples.iter().sum();
+
+        Stats {
+            samples: samples.len(),
+            mean_ns: (total.as_nanos() / count as u128) as u64,
+            median_ns: nanos(samples.get(samples.len() / 2)),
+            // nearest rank
+            p99_ns: nanos(samples.get((count * 99).div_ceil(100) - 1)),
+        }
+    }
+
+    fn mean(&self) -> Duration {
+        Duration::from_nanos(self.mean_ns)
+    }
+}
+
+// relative to the current directory, like the puzzle inputs
+pub fn default_baseline_path() -> PathBuf {
+    PathBuf::from("bench_baseline.json")
+}
+
+pub fn bench(
+    day: Option<u8>,
+    iterations: usize,
+    baseline_path: &Path,
+    save: bool,
+) -> Result<(), Box<dyn Error>> {
+    let baseline = load_baseline(baseline_path)?;
+    let mut results = baseline.clone();
+    let mut regressions = 0;
+
+    let solutions: Vec<_> = match day {
+        Some(day) => {
+            let solution = days::get(day).ok_or(format!("Day {} is not solved yet", day))?;
+            vec![(day, solution)]
+        }
+        None => days::all().collect(),
+    };
+
+    for (day, solution) in solutions {
+        let name = format!("day{:02}", day);
+        println!("Day {:02}", day);
+
+        let input = input::for_day(day)?;
+        let stats = bench_day(solution.as_ref(), &input, iterations)?;
+
+        for (label, current) in &stats {
+            let previous = baseline.get(&name).and_then(|day| day.get(label));
+            if let Some(previous) = previous {
+                let change = current.mean_ns as f64 / previous.mean_ns.max(1) as f64 - 1.0;
+                if change > REGRESSION_THRESHOLD {
+                    regressions += 1;
+                    println!(
+                        "    {} regressed: {:.2?} -> {:.2?} ({:+.1}%)",
+                        label,
+                        previous.mean(),
+                        current.mean(),
+                        change * 100.0
+                    );
+                }
+            }
+        }
+
+        results.insert(name, stats);
+    }
+
+    if save {
+        std::fs::write(baseline_path, serde_json::to_string_pretty(&results)?)?;
+        println!("Baseline saved to {}", baseline_path.display());
+    } else if regressions > 0 {
+        return Err(format!(
+            "{} measurements are more than {:.0}% slower than the baseline",
+            regressions,
+            REGRESSION_THRESHOLD * 100.0
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+fn load_baseline(path: &Path) -> Result<Baseline, Box<dyn Error>> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => serde_json::from_str(&content)
+            .map_err(|e| format!("Invalid baseline {}: {}", path.display(), e).into()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Baseline::new()),
+        Err(e) => Err(format!("Unable to read {}: {}", path.display(), e).into()),
+    }
+}
+
+fn bench_day(
+    solution: &dyn DynSolution,
+    input: &str,
+    iterations: usize,
+) -> Result<BTreeMap<String, Stats>, Box<dyn Error>> {
+    let mut results = BTreeMap::new();
+
+    let stats = measure(iterations, || solution.parse_boxed(input));
+    let parsed = solution.parse_boxed(input)?;
+    print_stats("parse", &stats, None);
+    results.insert("parse".to_string(), stats);
+
+    let alternatives = solution.alternatives();
+
+    for part in Part::ALL {
+        let label = format!("part {}", part);
+        let answer = match solution.solve(part, parsed.as_ref()) {
+            Ok(answer) => answer,
+            Err(e) => {
+                println!("  {:<30} error: {}", label, e);
+                continue;
+            }
+        };
+
+        let main = measure(iterations, || solution.solve(part, parsed.as_ref()));
+        print_stats(&label, &main, None);
+
+        for (name, _) in alternatives.iter().filter(|(_, p)| *p == part) {
+            let alternative_label = format!("{} {}", label, name);
+            match solution.solve_alternative(name, part, parsed.as_ref()) {
+                Ok(other) if other != answer => {
+                    println!(
+                        "  {:<30} wrong answer: {} instead of {}",
+                        alternative_label, other, answer
+                    );
+                }
+                Ok(_) => {
+                    let stats = measure(iterations, || {
+                        solution.solve_alternative(name, part, parsed.as_ref())
+                    });
+                    print_stats(&alternative_label, &stats, Some((&label, &main)));
+                    results.insert(alternative_label, stats);
+                }
+                Err(e) => println!("  {:<30} error: {}", alternative_label, e),
+            }
+        }
+
+        results.insert(label, main);
+    }
+
+    Ok(results)
+}
+
+fn measure<T>(iterations: usize, mut f: impl FnMut() -> T) -> Stats {
+    let mut samples = Vec::with_capacity(iterations);
+    let start = Instant::now();
+
+    while samples.len() < iterations.max(1) {
+        let sample_start = Instant::now();
+        std::hint::black_box(f());
+        samples.push(sample_start.elapsed());
+
+        if start.elapsed() > TIME_BUDGET {
+            break;
+        }
+    }
+
+    Stats::from_samples(samples)
+}
+
+fn print_stats(label: &str, stats: &Stats, reference: Option<(&str, &Stats)>) {
+    let format = |nanos: u64| format!("{:.2?}", Duration::from_nanos(nanos));
+    let comparison = reference
+        .map(|(name, reference)| {
+            format!(
+                "  {:.2}x {}",
+                stats.mean_ns as f64 / reference.mean_ns.max(1) as f64,
+                name
+            )
+        })
+        .unwrap_or_default();
+
+    println!(
+        "  {:<30} mean {:>10}  median {:>10}  p99 {:>10}  ({} runs){}",
+        label,
+        format(stats.mean_ns),
+        format(stats.median_ns),
+        format(stats.p99_ns),
+        stats.samples,
+        comparison
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|v| Duration::from_millis(*v)).collect()
+    }
+
+    #[test]
+    fn test_stats_from_samples() {
+        let stats = Stats::from_samples(millis(&[5, 1, 3, 2, 4]));
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.mean_ns, 3_000_000);
+        assert_eq!(stats.median_ns, 3_000_000);
+        assert_eq!(stats.p99_ns, 5_000_000);
+    }
+
+    #[test]
+    fn test_p99_ignores_the_slowest_outlier() {
+        let mut samples = vec![1; 199];
+        samples.push(1000);
+        let stats = Stats::from_samples(millis(&samples));
+        assert_eq!(stats.p99_ns, 1_000_000);
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let mut baseline = Baseline::new();
+        baseline
+            .entry("day01".to_string())
+            .or_default()
+            .insert("parse".to_string(), Stats::from_samples(millis(&[1, 2])));
+
+        let json = serde_json::to_string(&baseline).unwrap();
+        assert_eq!(serde_json::from_str::<Baseline>(&json).unwrap(), baseline);
+    }
+}
@@ -0,0 +1,42 @@
+use common::DynSolution;
+
+pub fn get(day: u8) -> Option<Box<dyn DynSolution>> {
+    let solution: Box<dyn DynSolution> = match day {
+        1 => Box::new(day01::Day01),
+        2 => Box::new(day02::Day02),
+        3 => Box::new(day03::Day03),
+        4 => Box::new(day04::Day04),
+        5 => Box::new(day05::Day05),
+        6 => Box::new(day06::Day06),
+        7 => Box::new(day07::Day07::default()),
+        8 => Box::new(day08::Day08),
+        9 => Box::new(day09::Day09),
+        10 => Box::new(day10::Day10),
+        11 => Box::new(day11::Day11),
+        12 => Box::new(day12::Day12),
+        13 => Box::new(day13::Day13),
+        14 => Box::new(day14::Day14),
+        15 => Box::new(day15::Day15::default()),
+        16 => Box::new(day16::Day16),
+        _ => return None,
+    };
+
+    Some(solution)
+}
+
+pub fn all() -> impl Iterator<Item = (u8, Box<dyn DynSolution>)> {
+    (1..=25).filter_map(|day| get(day).map(|solution| (day, solution)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_day() {
+        assert!(get(0).is_none());
+        assert!(get(1).is_some());
+        assert!(get(16).is_some());
+        assert!(get(17).is_none());
+    }
+}
@@ -0,0 +1,44 @@
+use std::process::ExitCode;
+
+mod args;
+mod bench;
+mod days;
+mod run;
+mod verify;
+
+use args::Command;
+
+fn main() -> ExitCode {
+    let command = match args::parse(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("{}\n{}", e, args::USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let result = match command {
+        Command::Run { day, part, input } => run::run(day, part, input),
+        Command::Bench {
+            day,
+            iterations,
+            baseline,
+            save,
+        } => {
+            let baseline = baseline.unwrap_or_else(bench::default_baseline_path);
+            bench::bench(day, iterations, &baseline, save)
+        }
+        Command::Verify { day, answers } => {
+            let answers = answers.unwrap_or_else(verify::default_answers_path);
+            verify::verify(day, &answers)
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
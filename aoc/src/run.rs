@@ -0,0 +1,70 @@
+use std::error::Error;
+use std::time::{Duration, Instant};
+
+use common::input::{self, Source};
+use common::{DynSolution, Part};
+
+use crate::days;
+
+pub fn run(
+    day: Option<u8>,
+    part: Option<Part>,
+    input: Option<Source>,
+) -> Result<(), Box<dyn Error>> {
+    match day {
+        Some(day) => {
+            let solution = days::get(day).ok_or(format!("Day {} is not solved yet", day))?;
+            run_day(day, solution.as_ref(), part, input)?;
+            Ok(())
+        }
+        None => {
+            // a day that can't run doesn't stop the others
+            let mut total = Duration::ZERO;
+            for (day, solution) in days::all() {
+                match run_day(day, solution.as_ref(), part, None) {
+                    Ok(elapsed) => total += elapsed,
+                    Err(e) => eprintln!("  ERROR, {}", e),
+                }
+            }
+            println!("Total: {:?}", total);
+            Ok(())
+        }
+    }
+}
+
+fn run_day(
+    day: u8,
+    solution: &dyn DynSolution,
+    part: Option<Part>,
+    input: Option<Source>,
+) -> Result<Duration, Box<dyn Error>> {
+    println!("Day {:02}", day);
+
+    let input = input::load(&input.unwrap_or(Source::Default(day)))?;
+
+    let start = Instant::now();
+    let parsed = solution.parse_boxed(&input)?;
+    let mut elapsed = start.elapsed();
+    println!("  Parse: ({:?})", elapsed);
+
+    for current in Part::ALL {
+        if part.is_some_and(|part| part != current) {
+            continue;
+        }
+
+        let start = Instant::now();
+        let answer = solution.solve(current, parsed.as_ref());
+        let part_elapsed = start.elapsed();
+        elapsed += part_elapsed;
+
+        match answer {
+            Ok(answer) if answer.contains('\n') => {
+                println!("  Part {}: ({:?})\n{}", current, part_elapsed, answer)
+            }
+            Ok(answer) => println!("  Part {}: {} ({:?})", current, answer, part_elapsed),
+            Err(e) => eprintln!("  Part {}: error: {} ({:?})", current, e, part_elapsed),
+        }
+    }
+
+    Ok(elapsed)
+}
@@ -0,0 +1,199 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+use common::{input, DynSolution, Part};
+use serde::Deserialize;
+
+use crate::days;
+
+// Day name (e.g. "day05") -> expected answers
+pub type Answers = BTreeMap<String, DayAnswers>;
+
+#[derive(Debug, Default, PartialEq, Eq, Deserialize)]
+pub struct DayAnswers {
+    part1: Option<Answer>,
+    part2: Option<Answer>,
+}
+
+impl DayAnswers {
+    fn get(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text.trim_end()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Outcome {
+    Pass,
+    Fail { expected: String, actual: String },
+    Missing,
+    Error(String),
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Pass => write!(f, "pass"),
+            Outcome::Fail { expected, actual } if expected.contains('\n') => {
+                write!(f, "FAIL, expected\n{}\ngot\n{}", expected, actual)
+            }
+            Outcome::Fail { expected, actual } => {
+                write!(f, "FAIL, expected {} got {}", expected, actual)
+            }
+            Outcome::Missing => write!(f, "missing expected answer"),
+            Outcome::Error(e) => write!(f, "ERROR, {}", e),
+        }
+    }
+}
+
+// relative to the current directory, like the puzzle inputs
+pub fn default_answers_path() -> PathBuf {
+    PathBuf::from("answers.toml")
+}
+
+pub fn load_answers(path: &Path) -> Result<Answers, Box<dyn Error>> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Unable to read {}: {}", path.display(), e))?;
+    Ok(toml::from_str(&content).map_err(|e| format!("Invalid {}: {}", path.display(), e))?)
+}
+
+pub fn verify(day: Option<u8>, answers_path: &Path) -> Result<(), Box<dyn Error>> {
+    let answers = load_answers(answers_path)?;
+    let solutions: Vec<_> = match day {
+        Some(day) => {
+            let solution = days::get(day).ok_or(format!("Day {} is not solved yet", day))?;
+            vec![(day, solution)]
+        }
+        None => days::all().collect(),
+    };
+
+    let mut failures = 0;
+    let mut missing = 0;
+    for (day, solution) in solutions {
+        println!("Day {:02}", day);
+        let expected = answers.get(&format!("day{:02}", day));
+
+        for (part, outcome) in verify_day(day, solution.as_ref(), expected) {
+            println!("  Part {}: {}", part, outcome);
+            match outcome {
+                Outcome::Pass => {}
+                Outcome::Missing => missing += 1,
+                Outcome::Fail { .. } | Outcome::Error(_) => failures += 1,
+            }
+        }
+    }
+
+    println!("{} failed, {} missing", failures, missing);
+    if failures > 0 {
+        return Err(format!("{} answers are wrong", failures).into());
+    }
+
+    Ok(())
+}
+
+fn verify_day(
+    day: u8,
+    solution: &dyn DynSolution,
+    expected: Option<&DayAnswers>,
+) -> Vec<(Part, Outcome)> {
+    let parsed = match input::for_day(day)
+        .map_err(|e| e.to_string())
+        .and_then(|input| solution.parse_boxed(&input).map_err(|e| e.to_string()))
+    {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            return Part::ALL
+                .into_iter()
+                .map(|part| (part, Outcome::Error(e.clone())))
+                .collect()
+        }
+    };
+
+    Part::ALL
+        .into_iter()
+        .map(|part| {
+            let expected = expected.and_then(|answers| answers.get(part));
+            let outcome = check(expected, solution.solve(part, parsed.as_ref()));
+            (part, outcome)
+        })
+        .collect()
+}
+
+fn check(expected: Option<&Answer>, actual: Result<String, impl Display>) -> Outcome {
+    match (expected, actual) {
+        (Some(expected), Ok(actual)) if expected.to_string() == actual.trim_end() => Outcome::Pass,
+        (Some(expected), Ok(actual)) => Outcome::Fail {
+            expected: expected.to_string(),
+            actual,
+        },
+        (Some(_), Err(e)) => Outcome::Error(e.to_string()),
+        (None, _) => Outcome::Missing,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_answers_file() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../answers.toml");
+        let answers = load_answers(&path).unwrap();
+        let day10 = &answers["day10"];
+
+        assert_eq!(answers["day01"].part1, Some(Answer::Number(69206)));
+        assert_eq!(
+            answers["day05"].part2,
+            Some(Answer::Text("CNSCZWLVT".to_string()))
+        );
+        assert_eq!(day10.part2.as_ref().unwrap().to_string().lines().count(), 6);
+        assert_eq!(answers["day16"].part2, Some(Answer::Number(2464)));
+    }
+
+    #[test]
+    fn test_check() {
+        let number = Answer::Number(42);
+        let text = Answer::Text("#.\n.#\n".to_string());
+
+        assert_eq!(
+            check(Some(&number), Ok::<_, String>("42".into())),
+            Outcome::Pass
+        );
+        assert_eq!(
+            check(Some(&text), Ok::<_, String>("#.\n.#".into())),
+            Outcome::Pass
+        );
+        assert_eq!(
+            check(Some(&number), Ok::<_, String>("41".into())),
+            Outcome::Fail {
+                expected: "42".into(),
+                actual: "41".into()
+            }
+        );
+        assert_eq!(
+            check(Some(&number), Err("boom")),
+            Outcome::Error("boom".into())
+        );
+        assert_eq!(check(None, Ok::<_, String>("42".into())), Outcome::Missing);
+    }
+}
@@ -0,0 +1,93 @@
+use crate::Point;
+
+// Screen directions: `Up` decreases y and `Right` increases x, like moving
+// through the rows and columns of a `Matrix2D`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    // clockwise, starting from `Up`
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    fn rotate(self, eighths: usize) -> Direction {
+        Self::ALL[(self as usize + eighths) % 8]
+    }
+
+    pub fn turn_right(self) -> Direction {
+        self.rotate(2)
+    }
+
+    pub fn turn_left(self) -> Direction {
+        self.rotate(6)
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.rotate(4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    pub fn unit(self) -> Point<isize> {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::UpRight => Point::new(1, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::DownRight => Point::new(1, 1),
+            Direction::Down => Point::new(0, 1),
+            Direction::DownLeft => Point::new(-1, 1),
+            Direction::Left => Point::new(-1, 0),
+            Direction::UpLeft => Point::new(-1, -1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::UpLeft.turn_right(), Direction::UpRight);
+        assert_eq!(Direction::DownRight.opposite(), Direction::UpLeft);
+
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.opposite().unit(), -direction.unit());
+        }
+    }
+
+    #[test]
+    fn test_diagonals() {
+        assert!(Direction::ORTHOGONAL.iter().all(|d| !d.is_diagonal()));
+        assert_eq!(Direction::ALL.iter().filter(|d| d.is_diagonal()).count(), 4);
+    }
+}
@@ -0,0 +1,170 @@
+use std::fmt::Display;
+
+pub type AocResult<T> = Result<T, AocError>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AocError {
+    Parse {
+        line: Option<usize>,
+        column: Option<usize>,
+        message: String,
+    },
+    InvalidState(String),
+    NoSolution(String),
+}
+
+impl AocError {
+    pub fn parse(message: impl Into<String>) -> Self {
+        AocError::Parse {
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn invalid_state(message: impl Into<String>) -> Self {
+        AocError::InvalidState(message.into())
+    }
+
+    pub fn no_solution(message: impl Into<String>) -> Self {
+        AocError::NoSolution(message.into())
+    }
+
+    // Positions are 1-based. An error which already knows its line (e.g. one
+    // coming from a nested parser) keeps it
+    pub fn at_line(self, line: usize) -> Self {
+        match self {
+            AocError::Parse {
+                line: None,
+                column,
+                message,
+            } => AocError::Parse {
+                line: Some(line),
+                column,
+                message,
+            },
+            other => other,
+        }
+    }
+
+    pub fn at_column(self, column: usize) -> Self {
+        match self {
+            AocError::Parse {
+                line,
+                column: None,
+                message,
+            } => AocError::Parse {
+                line,
+                column: Some(column),
+                message,
+            },
+            other => other,
+        }
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocError::Parse {
+                line: Some(line),
+                column: Some(column),
+                message,
+            } => write!(f, "Parse error at {}:{}: {}", line, column, message),
+            AocError::Parse {
+                line: Some(line),
+                column: None,
+                message,
+            } => write!(f, "Parse error at line {}: {}", line, message),
+            AocError::Parse {
+                line: None,
+                column: Some(column),
+                message,
+            } => write!(f, "Parse error at column {}: {}", column, message),
+            AocError::Parse { message, .. } => write!(f, "Parse error: {}", message),
+            AocError::InvalidState(message) => write!(f, "Invalid puzzle state: {}", message),
+            AocError::NoSolution(message) => write!(f, "No solution found: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
+
+impl From<std::num::ParseIntError> for AocError {
+    fn from(error: std::num::ParseIntError) -> Self {
+        AocError::parse(error.to_string())
+    }
+}
+
+// Parses every line of `input`, tagging errors with the line they come from
+pub fn parse_lines<T>(input: &str) -> AocResult<Vec<T>>
+where
+    T: std::str::FromStr<Err = AocError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| line.parse().map_err(|e: AocError| e.at_line(index + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Digit(u32);
+
+    impl std::str::FromStr for Digit {
+        type Err = AocError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s.chars().position(|c| !c.is_ascii_digit()) {
+                Some(index) => Err(AocError::parse("Expected a digit").at_column(index + 1)),
+                None => Ok(Digit(s.parse()?)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            AocError::parse("Bad input").to_string(),
+            "Parse error: Bad input"
+        );
+        assert_eq!(
+            AocError::parse("Bad input").at_line(3).to_string(),
+            "Parse error at line 3: Bad input"
+        );
+        assert_eq!(
+            AocError::parse("Bad input").at_column(5).to_string(),
+            "Parse error at column 5: Bad input"
+        );
+        assert_eq!(
+            AocError::parse("Bad input")
+                .at_column(5)
+                .at_line(3)
+                .to_string(),
+            "Parse error at 3:5: Bad input"
+        );
+        assert_eq!(
+            AocError::no_solution("Empty sky").to_string(),
+            "No solution found: Empty sky"
+        );
+    }
+
+    #[test]
+    fn test_at_line_keeps_inner_position() {
+        let error = AocError::parse("Bad input").at_line(2).at_line(7);
+        assert_eq!(error.to_string(), "Parse error at line 2: Bad input");
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let digits: Vec<Digit> = parse_lines("1\n22\n333").unwrap();
+        assert_eq!(digits.iter().map(|d| d.0).sum::<u32>(), 356);
+
+        let error = parse_lines::<Digit>("1\n2x\n3").unwrap_err();
+        assert_eq!(error.to_string(), "Parse error at 2:2: Expected a digit");
+    }
+}
@@ -0,0 +1,189 @@
+use std::iter;
+
+use crate::{Direction, Point};
+
+// An axis-aligned rectangle of lattice points, with both corners included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundingBox {
+    pub min: Point<isize>,
+    pub max: Point<isize>,
+}
+
+impl BoundingBox {
+    pub fn new(a: Point<isize>, b: Point<isize>) -> Self {
+        Self {
+            min: Point::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Point::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    pub fn from_points(points: impl IntoIterator<Item = Point<isize>>) -> Option<Self> {
+        points.into_iter().fold(None, |bounds, point| {
+            Some(match bounds {
+                None => Self::new(point, point),
+                Some(bounds) => bounds.include(point),
+            })
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.min.x.abs_diff(self.max.x) + 1
+    }
+
+    pub fn height(&self) -> usize {
+        self.min.y.abs_diff(self.max.y) + 1
+    }
+
+    pub fn contains(&self, point: &Point<isize>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    // The smallest box that also covers `point`
+    pub fn include(&self, point: Point<isize>) -> Self {
+        self.union(&Self::new(point, point))
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            min: Point::new(self.min.x.min(other.min.x), self.min.y.min(other.min.y)),
+            max: Point::new(self.max.x.max(other.max.x), self.max.y.max(other.max.y)),
+        }
+    }
+
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        let min = Point::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y));
+        let max = Point::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y));
+        (min.x <= max.x && min.y <= max.y).then_some(Self { min, max })
+    }
+
+    // Every point in the box, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point<isize>> {
+        let Self { min, max } = *self;
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point::new(x, y)))
+    }
+}
+
+// Bresenham's line from `from` to `to`, both included
+pub fn line(from: Point<isize>, to: Point<isize>) -> impl Iterator<Item = Point<isize>> {
+    let dx = (to.x - from.x).abs();
+    let dy = -(to.y - from.y).abs();
+    let step = (to - from).signum();
+    let mut error = dx + dy;
+    let mut next = Some(from);
+
+    iter::from_fn(move || {
+        let point = next?;
+        next = (point != to).then(|| {
+            let mut following = point;
+            let doubled_error = 2 * error;
+            if doubled_error >= dy {
+                error += dy;
+                following.x += step.x;
+            }
+            if doubled_error <= dx {
+                error += dx;
+                following.y += step.y;
+            }
+            following
+        });
+        Some(point)
+    })
+}
+
+// The points at exactly `radius` Manhattan distance from `center`, clockwise
+// from the one straight above it
+pub fn manhattan_border(center: Point<isize>, radius: usize) -> impl Iterator<Item = Point<isize>> {
+    let top = center + Direction::Up.unit() * radius as isize;
+    let sides = [
+        Direction::DownRight,
+        Direction::DownLeft,
+        Direction::UpLeft,
+        Direction::UpRight,
+    ];
+
+    let steps = (0..4 * radius).scan(top, move |point, step| {
+        *point += sides[step / radius];
+        Some(*point)
+    });
+
+    iter::once(top).chain(steps).take((4 * radius).max(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p(x: isize, y: isize) -> Point<isize> {
+        Point::new(x, y)
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let a = BoundingBox::new(p(3, 4), p(0, 0));
+        let b = BoundingBox::new(p(2, -1), p(6, 2));
+
+        assert_eq!((a.width(), a.height()), (4, 5));
+        assert!(a.contains(&p(3, 0)));
+        assert!(!a.contains(&p(4, 0)));
+        assert_eq!(a.union(&b), BoundingBox::new(p(0, -1), p(6, 4)));
+        assert_eq!(a.intersect(&b), Some(BoundingBox::new(p(2, 0), p(3, 2))));
+        assert_eq!(a.intersect(&BoundingBox::new(p(4, 0), p(5, 5))), None);
+        assert_eq!(a.points().count(), 20);
+        assert_eq!(
+            BoundingBox::from_points([p(1, 1), p(-1, 3), p(0, 0)]),
+            Some(BoundingBox::new(p(-1, 0), p(1, 3)))
+        );
+        assert_eq!(BoundingBox::from_points([]), None);
+    }
+
+    #[test]
+    fn test_straight_lines() {
+        assert_eq!(
+            line(p(2, 1), p(2, 4)).collect::<Vec<_>>(),
+            vec![p(2, 1), p(2, 2), p(2, 3), p(2, 4)]
+        );
+        assert_eq!(
+            line(p(0, 0), p(-2, 0)).collect::<Vec<_>>(),
+            vec![p(0, 0), p(-1, 0), p(-2, 0)]
+        );
+        assert_eq!(
+            line(p(0, 0), p(-2, 2)).collect::<Vec<_>>(),
+            vec![p(0, 0), p(-1, 1), p(-2, 2)]
+        );
+        assert_eq!(line(p(5, 5), p(5, 5)).collect::<Vec<_>>(), vec![p(5, 5)]);
+    }
+
+    #[test]
+    fn test_any_angle_lines() {
+        assert_eq!(
+            line(p(0, 0), p(4, 2)).collect::<Vec<_>>(),
+            vec![p(0, 0), p(1, 1), p(2, 1), p(3, 2), p(4, 2)]
+        );
+
+        for to in [p(7, -3), p(-2, 9), p(-6, -5), p(1, 8)] {
+            let points = line(p(0, 0), to).collect::<Vec<_>>();
+            assert_eq!(points.len(), p(0, 0).chebyshev_distance(&to) + 1);
+            assert_eq!(points.last(), Some(&to));
+            assert!(points
+                .windows(2)
+                .all(|w| w[0].chebyshev_distance(&w[1]) == 1));
+        }
+    }
+
+    #[test]
+    fn test_manhattan_border() {
+        let center = p(3, -2);
+        assert_eq!(
+            manhattan_border(center, 0).collect::<Vec<_>>(),
+            vec![center]
+        );
+        assert_eq!(
+            manhattan_border(center, 1).collect::<Vec<_>>(),
+            vec![p(3, -3), p(4, -2), p(3, -1), p(2, -2)]
+        );
+
+        let border = manhattan_border(center, 5).collect::<Vec<_>>();
+        assert_eq!(border.len(), 20);
+        assert!(border.iter().all(|point| center.cab_distance(point) == 5));
+    }
+}
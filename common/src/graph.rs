@@ -0,0 +1,189 @@
+use std::fmt::Write;
+
+// GraphViz attributes for a node, like ("label", "AA") or ("color", "red")
+pub type Attributes = Vec<(&'static str, String)>;
+
+// Nodes connected by edges, both referred to by the index a node was added at
+#[derive(Debug, Clone)]
+pub struct Graph<N> {
+    nodes: Vec<N>,
+    edges: Vec<Vec<usize>>,
+    directed: bool,
+}
+
+impl<N> Graph<N> {
+    pub fn directed() -> Self {
+        Self {
+            nodes: vec![],
+            edges: vec![],
+            directed: true,
+        }
+    }
+
+    pub fn undirected() -> Self {
+        Self {
+            directed: false,
+            ..Self::directed()
+        }
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn add_node(&mut self, node: N) -> usize {
+        self.nodes.push(node);
+        self.edges.push(vec![]);
+        self.nodes.len() - 1
+    }
+
+    // Adding the same edge again changes nothing. Undirected edges are kept
+    // in both directions, so `a -- b` is the same edge as `b -- a`.
+    pub fn add_edge(&mut self, from: usize, to: usize) {
+        assert!(
+            from < self.len() && to < self.len(),
+            "Edge {} -> {} between unknown nodes",
+            from,
+            to
+        );
+        if !self.edges[from].contains(&to) {
+            self.edges[from].push(to);
+        }
+        if !self.directed && !self.edges[to].contains(&from) {
+            self.edges[to].push(from);
+        }
+    }
+
+    pub fn node(&self, index: usize) -> Option<&N> {
+        self.nodes.get(index)
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = (usize, &N)> {
+        self.nodes.iter().enumerate()
+    }
+
+    pub fn neighbors(&self, index: usize) -> &[usize] {
+        &self.edges[index]
+    }
+
+    // Every edge once, even the undirected ones
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.edges.iter().enumerate().flat_map(move |(from, tos)| {
+            tos.iter()
+                .filter(move |to| self.directed || from <= **to)
+                .map(move |to| (from, *to))
+        })
+    }
+
+    /// The graph in GraphViz's DOT language, with `attributes` giving the
+    /// label, color and so on of each node. `dot -Tsvg` turns it into a
+    /// picture.
+    pub fn to_dot<F>(&self, name: &str, attributes: F) -> String
+    where
+        F: Fn(&N) -> Attributes,
+    {
+        let (kind, arrow) = match self.directed {
+            true => ("digraph", "->"),
+            false => ("graph", "--"),
+        };
+
+        let mut dot = String::new();
+        // writing to a String can't fail
+        writeln!(dot, "{} {} {{", kind, quote(name)).unwrap();
+        for (index, node) in self.nodes() {
+            let attributes = attributes(node)
+                .into_iter()
+                .map(|(key, value)| format!("{}={}", key, quote(&value)))
+                .collect::<Vec<_>>();
+            match attributes.is_empty() {
+                true => writeln!(dot, "    n{};", index).unwrap(),
+                false => writeln!(dot, "    n{} [{}];", index, attributes.join(", ")).unwrap(),
+            }
+        }
+        for (from, to) in self.edges() {
+            writeln!(dot, "    n{} {} n{};", from, arrow, to).unwrap();
+        }
+        dot.push_str("}\n");
+
+        dot
+    }
+}
+
+fn quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_undirected_edges() {
+        let mut graph = Graph::undirected();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+        graph.add_edge(c, b);
+
+        assert_eq!(graph.neighbors(b), &[a, c]);
+        assert_eq!(graph.edges().collect::<Vec<_>>(), vec![(0, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn test_directed_edges() {
+        let mut graph = Graph::directed();
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+        graph.add_edge(a, b);
+
+        assert_eq!(graph.neighbors(a), &[b]);
+        assert_eq!(graph.edges().collect::<Vec<_>>(), vec![(0, 1), (1, 0)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_edge_to_unknown_node() {
+        let mut graph = Graph::directed();
+        let a = graph.add_node(());
+        graph.add_edge(a, 1);
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let mut graph = Graph::directed();
+        let root = graph.add_node("/");
+        let file = graph.add_node("say \"hi\"");
+        graph.add_edge(root, file);
+
+        let dot = graph.to_dot("tree", |name| match *name {
+            "/" => vec![],
+            _ => vec![("label", name.to_string()), ("shape", "box".to_string())],
+        });
+        assert_eq!(
+            dot,
+            "digraph \"tree\" {\n    \
+             n0;\n    \
+             n1 [label=\"say \\\"hi\\\"\", shape=\"box\"];\n    \
+             n0 -> n1;\n\
+             }\n"
+        );
+
+        let mut graph = Graph::undirected();
+        graph.add_node(1);
+        graph.add_node(2);
+        graph.add_edge(1, 0);
+        assert!(graph.to_dot("g", |_| vec![]).contains("    n0 -- n1;\n"));
+    }
+}
@@ -1,3 +1,4 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Color {
     Red,
     Blue,
@@ -8,6 +9,7 @@ pub enum Color {
     Magenta,
     Yellow,
     Cyan,
+    Rgb(u8, u8, u8),
 }
 
 impl Color {
@@ -22,6 +24,7 @@ impl Color {
             Self::Magenta => (255, 0, 255),
             Self::Yellow => (255, 255, 0),
             Self::Cyan => (0, 255, 255),
+            Self::Rgb(r, g, b) => (*r, *g, *b),
         }
     }
 }
@@ -8,7 +8,7 @@ pub struct Image {
 impl Image {
     pub fn new(width: u32, height: u32) -> Self {
         Self {
-            data: RgbImage::new(width as u32, height as u32),
+            data: RgbImage::new(width, height),
         }
     }
 
@@ -29,8 +29,8 @@ impl Image {
     pub fn write_to_file(&self, filename: &str) -> Result<(), std::io::Error> {
         let mut file = File::create(filename)?;
         let header = format!("P6 {} {} 255\n", self.width, self.height);
-        file.write(header.as_bytes())?;
-        file.write(&self.data)?;
+        file.write_all(header.as_bytes())?;
+        file.write_all(&self.data)?;
         Ok(())
     }
 }
@@ -0,0 +1,105 @@
+use std::fmt::Display;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Default(u8),
+    Path(PathBuf),
+    Stdin,
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound(PathBuf),
+    Io(Option<PathBuf>, std::io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::NotFound(path) => write!(f, "Input file not found: {}", path.display()),
+            InputError::Io(Some(path), e) => write!(f, "Unable to read {}: {}", path.display(), e),
+            InputError::Io(None, e) => write!(f, "Unable to read from stdin: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+pub fn load(source: &Source) -> Result<String, InputError> {
+    match source {
+        Source::Default(day) => from_path(default_path(*day)),
+        Source::Path(path) => from_path(path),
+        Source::Stdin => from_stdin(),
+    }
+}
+
+pub fn for_day(day: u8) -> Result<String, InputError> {
+    load(&Source::Default(day))
+}
+
+pub fn from_path(path: impl AsRef<Path>) -> Result<String, InputError> {
+    let path = path.as_ref();
+    std::fs::read_to_string(path)
+        .map(|raw| normalise(&raw))
+        .map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => InputError::NotFound(path.to_path_buf()),
+            _ => InputError::Io(Some(path.to_path_buf()), e),
+        })
+}
+
+pub fn from_stdin() -> Result<String, InputError> {
+    let mut raw = String::new();
+    std::io::stdin()
+        .read_to_string(&mut raw)
+        .map_err(|e| InputError::Io(None, e))?;
+    Ok(normalise(&raw))
+}
+
+/// Where `dayNN/input.txt` folders are looked up, unless overridden
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+// `AOC_INPUT_DIR` if it's set, the current directory otherwise
+pub fn input_dir() -> PathBuf {
+    std::env::var_os(INPUT_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_default()
+}
+
+pub fn default_path(day: u8) -> PathBuf {
+    input_dir().join(format!("day{:02}", day)).join("input.txt")
+}
+
+// Converts CRLF line endings and drops the trailing newlines, so that
+// solutions see the same text regardless of how the input was saved
+pub fn normalise(raw: &str) -> String {
+    raw.replace("\r\n", "\n").trim_end_matches('\n').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalise_crlf() {
+        assert_eq!(normalise("1\r\n2\r\n\r\n3\r\n"), "1\n2\n\n3");
+    }
+
+    #[test]
+    fn test_normalise_trailing_newlines() {
+        assert_eq!(normalise("1\n2\n\n\n"), "1\n2");
+        assert_eq!(normalise("    [D]\n"), "    [D]");
+    }
+
+    #[test]
+    fn test_default_path() {
+        assert!(default_path(5).ends_with("day05/input.txt"));
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let result = from_path("this/file/does/not/exist.txt");
+        assert!(matches!(result, Err(InputError::NotFound(_))));
+    }
+}
@@ -0,0 +1,302 @@
+use std::ops::RangeInclusive;
+
+// Integers that can be stepped one at a time, so that ranges like 1..=3 and
+// 4..=6 can be merged into 1..=6
+pub trait Discrete: Copy + Ord {
+    fn next(self) -> Self;
+    fn prev(self) -> Self;
+    // how many values there are in start..=end
+    fn count(start: Self, end: Self) -> usize;
+}
+
+macro_rules! impl_discrete {
+    ($($t:ty),*) => {
+        $(impl Discrete for $t {
+            fn next(self) -> Self {
+                self + 1
+            }
+
+            fn prev(self) -> Self {
+                self - 1
+            }
+
+            fn count(start: Self, end: Self) -> usize {
+                (end as i128 - start as i128 + 1) as usize
+            }
+        })*
+    };
+}
+
+impl_discrete!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+// Whether every value of `inner` is in `outer`, like `RangeSet::covers`
+// without building a set
+pub fn covers<T: Ord>(outer: &RangeInclusive<T>, inner: &RangeInclusive<T>) -> bool {
+    inner.is_empty() || (outer.start() <= inner.start() && outer.end() >= inner.end())
+}
+
+// Whether `a` and `b` have any value in common
+pub fn overlaps<T: Ord>(a: &RangeInclusive<T>, b: &RangeInclusive<T>) -> bool {
+    !a.is_empty() && !b.is_empty() && a.start() <= b.end() && b.start() <= a.end()
+}
+
+// A set of integers stored as sorted, disjoint and non-adjacent ranges
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeSet<T> {
+    ranges: Vec<RangeInclusive<T>>,
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self { ranges: vec![] }
+    }
+}
+
+impl<T: Discrete> RangeSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn ranges(&self) -> impl Iterator<Item = &RangeInclusive<T>> {
+        self.ranges.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    // The number of values in the set
+    pub fn len(&self) -> usize {
+        self.ranges
+            .iter()
+            .map(|range| T::count(*range.start(), *range.end()))
+            .sum()
+    }
+
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = (*range.start(), *range.end());
+        if start > end {
+            return;
+        }
+
+        let mut before = vec![];
+        let mut after = vec![];
+        for existing in self.ranges.drain(..) {
+            // comparing first keeps next() from overflowing
+            if *existing.end() < start && existing.end().next() < start {
+                before.push(existing);
+            } else if *existing.start() > end && end.next() < *existing.start() {
+                after.push(existing);
+            } else {
+                start = start.min(*existing.start());
+                end = end.max(*existing.end());
+            }
+        }
+
+        before.push(start..=end);
+        before.append(&mut after);
+        self.ranges = before;
+    }
+
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = (*range.start(), *range.end());
+        if start > end {
+            return;
+        }
+
+        self.ranges = self
+            .ranges
+            .drain(..)
+            .flat_map(|existing| {
+                if *existing.end() < start || *existing.start() > end {
+                    return vec![existing];
+                }
+
+                let mut pieces = vec![];
+                if *existing.start() < start {
+                    pieces.push(*existing.start()..=start.prev());
+                }
+                if *existing.end() > end {
+                    pieces.push(end.next()..=*existing.end());
+                }
+                pieces
+            })
+            .collect();
+    }
+
+    // the first range that doesn't end before `value`
+    fn range_from(&self, value: T) -> Option<&RangeInclusive<T>> {
+        let index = self.ranges.partition_point(|range| *range.end() < value);
+        self.ranges.get(index)
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        self.range_from(*value)
+            .is_some_and(|range| range.start() <= value)
+    }
+
+    // Whether every value of `range` is in the set
+    pub fn covers(&self, range: &RangeInclusive<T>) -> bool {
+        range.is_empty()
+            || self
+                .range_from(*range.start())
+                .is_some_and(|found| found.start() <= range.start() && found.end() >= range.end())
+    }
+
+    // Whether any value of `range` is in the set
+    pub fn overlaps(&self, range: &RangeInclusive<T>) -> bool {
+        !range.is_empty()
+            && self
+                .range_from(*range.start())
+                .is_some_and(|found| found.start() <= range.end())
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        result.extend(other.ranges.iter().cloned());
+        result
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for range in &other.ranges {
+            result.remove(range.clone());
+        }
+        result
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let start = *a.start().max(b.start());
+            let end = *a.end().min(b.end());
+            if start <= end {
+                ranges.push(start..=end);
+            }
+            if a.end() < b.end() {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { ranges }
+    }
+
+    // The parts of `within` that aren't in the set
+    pub fn gaps(&self, within: RangeInclusive<T>) -> Self {
+        Self::from(within).difference(self)
+    }
+}
+
+impl<T: Discrete> From<RangeInclusive<T>> for RangeSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: Discrete> FromIterator<RangeInclusive<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Discrete> Extend<RangeInclusive<T>> for RangeSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[RangeInclusive<i32>]) -> RangeSet<i32> {
+        ranges.iter().cloned().collect()
+    }
+
+    fn ranges(set: &RangeSet<i32>) -> Vec<RangeInclusive<i32>> {
+        set.ranges().cloned().collect()
+    }
+
+    #[test]
+    fn test_insert_merges() {
+        let mut set = set(&[10..=12, 1..=3, 4..=5, 8..=8]);
+        assert_eq!(ranges(&set), vec![1..=5, 8..=8, 10..=12]);
+        assert_eq!(set.len(), 9);
+
+        // an empty range changes nothing
+        let (start, end) = (20, 19);
+        set.insert(start..=end);
+        assert_eq!(set.len(), 9);
+
+        set.insert(6..=11);
+        assert_eq!(ranges(&set), vec![1..=12]);
+    }
+
+    #[test]
+    fn test_insert_at_the_limits() {
+        let mut set = RangeSet::from(u8::MAX..=u8::MAX);
+        set.insert(0..=0);
+        set.insert(1..=254);
+        assert_eq!(set.ranges().collect::<Vec<_>>(), vec![&(0..=255)]);
+        assert_eq!(set.len(), 256);
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut set = set(&[1..=10, 15..=20]);
+        set.remove(4..=6);
+        set.remove(9..=16);
+        set.remove(20..=30);
+        assert_eq!(ranges(&set), vec![1..=3, 7..=8, 17..=19]);
+    }
+
+    #[test]
+    fn test_queries() {
+        let set = set(&[1..=5, 10..=12]);
+
+        assert!(set.contains(&5));
+        assert!(!set.contains(&6));
+        assert!(set.covers(&(2..=4)));
+        assert!(!set.covers(&(4..=10)));
+        assert!(set.overlaps(&(4..=10)));
+        assert!(!set.overlaps(&(6..=9)));
+        assert!(!set.overlaps(&(13..=20)));
+    }
+
+    #[test]
+    fn test_range_queries() {
+        assert!(covers(&(1..=7), &(2..=5)));
+        assert!(covers(&(2..=5), &(2..=5)));
+        assert!(!covers(&(1..=4), &(3..=6)));
+        #[allow(clippy::reversed_empty_ranges)]
+        let empty = 5..=4;
+        assert!(covers(&(1..=2), &empty));
+
+        assert!(overlaps(&(5..=7), &(7..=9)));
+        assert!(overlaps(&(2..=3), &(1..=4)));
+        assert!(!overlaps(&(1..=3), &(5..=8)));
+        assert!(!overlaps(&(1..=9), &empty));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[1..=5, 10..=12]);
+        let b = set(&[4..=10, 20..=21]);
+
+        assert_eq!(ranges(&a.union(&b)), vec![1..=12, 20..=21]);
+        assert_eq!(ranges(&a.intersection(&b)), vec![4..=5, 10..=10]);
+        assert_eq!(ranges(&a.difference(&b)), vec![1..=3, 11..=12]);
+        assert_eq!(ranges(&a.gaps(0..=15)), vec![0..=0, 6..=9, 13..=15]);
+        assert!(a.gaps(2..=4).is_empty());
+    }
+}
@@ -1,8 +1,33 @@
 #[cfg(any(feature = "png_image", feature = "ppm_image"))]
 pub mod image_output;
 
+pub mod direction;
+pub use direction::Direction;
+
+pub mod error;
+pub use error::{AocError, AocResult};
+
+pub mod geometry;
+pub use geometry::BoundingBox;
+
+pub mod graph;
+pub use graph::Graph;
+
+pub mod input;
+
+pub mod intervals;
+pub use intervals::RangeSet;
+
 pub mod matrix_2d;
 pub use matrix_2d::Matrix2D;
 
+pub mod pathfinding;
+
 pub mod point;
 pub use point::Point;
+
+pub mod solution;
+pub use solution::{Alternative, DynSolution, Part, PartResult, Solution};
+
+pub mod sparse_grid;
+pub use sparse_grid::SparseGrid;
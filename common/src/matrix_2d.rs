@@ -58,20 +58,63 @@ impl<T> Matrix2D<T> {
         }
     }
 
+    // builds a rows x cols matrix where every cell borrows the value at the
+    // coordinates returned by `source`
+    fn rearrange<F>(&self, rows: usize, cols: usize, source: F) -> Matrix2D<&T>
+    where
+        F: Fn(usize, usize) -> (usize, usize),
+    {
+        let data = (0..rows)
+            .flat_map(|row| (0..cols).map(move |col| (row, col)))
+            .map(|(row, col)| {
+                let (row, col) = source(row, col);
+                &self.data[row * self.cols + col]
+            })
+            .collect();
+
+        Matrix2D { rows, cols, data }
+    }
+
     pub fn transpose(&self) -> Matrix2D<&T> {
-        let mut data = Vec::with_capacity(self.data.len());
+        self.rearrange(self.cols, self.rows, |row, col| (col, row))
+    }
 
-        for row in 0..self.rows {
-            for col in 0..self.cols {
-                data[self.index_for(row, col).unwrap()] = self.get(col, row).unwrap();
-            }
-        }
+    pub fn rotate_cw(&self) -> Matrix2D<&T> {
+        self.rearrange(self.cols, self.rows, |row, col| (self.rows - 1 - col, row))
+    }
 
-        Matrix2D {
-            rows: self.cols,
-            cols: self.rows,
-            data: data,
-        }
+    pub fn rotate_ccw(&self) -> Matrix2D<&T> {
+        self.rearrange(self.cols, self.rows, |row, col| (col, self.cols - 1 - row))
+    }
+
+    // mirrors left and right
+    pub fn flip_horizontal(&self) -> Matrix2D<&T> {
+        self.rearrange(self.rows, self.cols, |row, col| (row, self.cols - 1 - col))
+    }
+
+    // mirrors top and bottom
+    pub fn flip_vertical(&self) -> Matrix2D<&T> {
+        self.rearrange(self.rows, self.cols, |row, col| (self.rows - 1 - row, col))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.rows, "Row {} out of bounds", row);
+        &self.data[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn col(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(col < self.cols, "Column {} out of bounds", col);
+        (0..self.rows).map(move |row| &self.data[row * self.cols + col])
+    }
+
+    pub fn rows_iter(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.rows).map(|row| self.row(row))
+    }
+
+    pub fn cols_iter(
+        &self,
+    ) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T> + ExactSizeIterator> {
+        (0..self.cols).map(|col| self.col(col))
     }
 
     pub fn find<F>(&self, f: F) -> Option<(usize, usize)>
@@ -107,10 +150,6 @@ impl<T> Matrix2D<T> {
     pub fn iter(&self) -> impl Iterator<Item = &T> {
         self.data.iter()
     }
-
-    pub fn into_iter(self) -> impl IntoIterator<Item = T> {
-        self.data.into_iter()
-    }
 }
 
 impl<T> Index<(usize, usize)> for Matrix2D<T> {
@@ -127,6 +166,15 @@ impl<T> IndexMut<(usize, usize)> for Matrix2D<T> {
     }
 }
 
+impl<T> IntoIterator for Matrix2D<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
 impl<T: Clone> Clone for Matrix2D<T> {
     fn clone(&self) -> Self {
         Self {
@@ -144,7 +192,7 @@ impl<T> From<Vec<Vec<T>>> for Matrix2D<T> {
 
         let data = input
             .into_iter()
-            .flat_map(|row| row.into_iter().map(|elem| elem).collect::<Vec<_>>())
+            .flat_map(|row| row.into_iter().collect::<Vec<_>>())
             .collect();
 
         Matrix2D { rows, cols, data }
@@ -171,7 +219,7 @@ impl<T: Display> Display for Matrix2D<T> {
             for col in 0..self.cols {
                 write!(f, "{}", self.get(row, col).unwrap())?;
             }
-            writeln!(f, "")?;
+            writeln!(f)?;
         }
 
         Ok(())
@@ -184,9 +232,69 @@ impl<T: Debug> Debug for Matrix2D<T> {
             for col in 0..self.cols {
                 write!(f, "{:?} ", self.get(row, col).unwrap())?;
             }
-            writeln!(f, "")?;
+            writeln!(f)?;
         }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matrix() -> Matrix2D<char> {
+        "abc\ndef".into()
+    }
+
+    #[test]
+    fn test_transpose() {
+        let matrix = matrix();
+        let transposed = matrix.transpose();
+        assert_eq!((transposed.rows(), transposed.cols()), (3, 2));
+        assert_eq!(transposed.to_string(), "ad\nbe\ncf\n");
+    }
+
+    #[test]
+    fn test_rotate() {
+        let matrix = matrix();
+        assert_eq!(matrix.rotate_cw().to_string(), "da\neb\nfc\n");
+        assert_eq!(matrix.rotate_ccw().to_string(), "cf\nbe\nad\n");
+        assert_eq!(
+            matrix.rotate_cw().rotate_cw().rotate_cw().to_string(),
+            matrix.rotate_ccw().to_string()
+        );
+    }
+
+    #[test]
+    fn test_flip() {
+        let matrix = matrix();
+        assert_eq!(matrix.flip_horizontal().to_string(), "cba\nfed\n");
+        assert_eq!(matrix.flip_vertical().to_string(), "def\nabc\n");
+    }
+
+    #[test]
+    fn test_rows_and_cols() {
+        let matrix = matrix();
+        assert_eq!(matrix.row(1), &['d', 'e', 'f']);
+        assert_eq!(matrix.col(2).collect::<String>(), "cf");
+        assert_eq!(matrix.col(0).rev().collect::<String>(), "da");
+        assert_eq!(
+            matrix.rows_iter().map(|row| row.len()).collect::<Vec<_>>(),
+            vec![3, 3]
+        );
+        assert_eq!(
+            matrix
+                .cols_iter()
+                .map(|col| col.collect::<String>())
+                .collect::<Vec<_>>(),
+            vec!["ad", "be", "cf"]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_col_out_of_bounds() {
+        let _ = matrix().col(3);
+    }
+}
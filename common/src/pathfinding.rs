@@ -0,0 +1,461 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+use crate::Matrix2D;
+
+// (row, col), the same coordinates used by `Matrix2D`
+pub type Cell = (usize, usize);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N> {
+    pub nodes: Vec<N>,
+    pub cost: usize,
+}
+
+impl<N> Path<N> {
+    pub fn steps(&self) -> usize {
+        self.nodes.len().saturating_sub(1)
+    }
+
+    pub fn start(&self) -> Option<&N> {
+        self.nodes.first()
+    }
+
+    pub fn end(&self) -> Option<&N> {
+        self.nodes.last()
+    }
+}
+
+// Everything reached by a search, with the cheapest cost from any of the
+// sources and the links needed to rebuild the paths
+#[derive(Debug, Clone)]
+pub struct Search<N> {
+    costs: HashMap<N, usize>,
+    parents: HashMap<N, N>,
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+    fn new() -> Self {
+        Self {
+            costs: HashMap::new(),
+            parents: HashMap::new(),
+        }
+    }
+
+    pub fn cost(&self, node: &N) -> Option<usize> {
+        self.costs.get(node).copied()
+    }
+
+    pub fn path_to(&self, node: &N) -> Option<Path<N>> {
+        let cost = self.cost(node)?;
+        let mut nodes = vec![node.clone()];
+        while let Some(parent) = self.parents.get(nodes.last().unwrap()) {
+            nodes.push(parent.clone());
+        }
+        nodes.reverse();
+
+        Some(Path { nodes, cost })
+    }
+
+    pub fn reached(&self) -> impl Iterator<Item = (&N, usize)> {
+        self.costs.iter().map(|(node, cost)| (node, *cost))
+    }
+}
+
+pub fn bfs<N, F, I>(sources: impl IntoIterator<Item = N>, neighbors: F) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    breadth_first(sources, neighbors, |_| false).0
+}
+
+pub fn bfs_to<N, F, I, G>(
+    sources: impl IntoIterator<Item = N>,
+    neighbors: F,
+    is_goal: G,
+) -> Option<Path<N>>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(&N) -> bool,
+{
+    let (search, goal) = breadth_first(sources, neighbors, is_goal);
+    goal.and_then(|goal| search.path_to(&goal))
+}
+
+pub fn dijkstra<N, F, I>(sources: impl IntoIterator<Item = N>, neighbors: F) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    best_first(sources, neighbors, |_| 0, |_| false).0
+}
+
+pub fn dijkstra_to<N, F, I, G>(
+    sources: impl IntoIterator<Item = N>,
+    neighbors: F,
+    is_goal: G,
+) -> Option<Path<N>>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, usize)>,
+    G: FnMut(&N) -> bool,
+{
+    let (search, goal) = best_first(sources, neighbors, |_| 0, is_goal);
+    goal.and_then(|goal| search.path_to(&goal))
+}
+
+// The heuristic must never overestimate the remaining cost, otherwise the
+// returned path might not be the cheapest one
+pub fn astar<N, F, I, H, G>(
+    sources: impl IntoIterator<Item = N>,
+    neighbors: F,
+    heuristic: H,
+    is_goal: G,
+) -> Option<Path<N>>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, usize)>,
+    H: FnMut(&N) -> usize,
+    G: FnMut(&N) -> bool,
+{
+    let (search, goal) = best_first(sources, neighbors, heuristic, is_goal);
+    goal.and_then(|goal| search.path_to(&goal))
+}
+
+fn breadth_first<N, F, I, G>(
+    sources: impl IntoIterator<Item = N>,
+    mut neighbors: F,
+    mut is_goal: G,
+) -> (Search<N>, Option<N>)
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(&N) -> bool,
+{
+    let mut search = Search::new();
+    let mut to_visit = VecDeque::new();
+
+    for source in sources {
+        if search.costs.insert(source.clone(), 0).is_none() {
+            to_visit.push_back(source);
+        }
+    }
+
+    while let Some(node) = to_visit.pop_front() {
+        if is_goal(&node) {
+            return (search, Some(node));
+        }
+
+        let cost = search.costs[&node] + 1;
+        for neighbor in neighbors(&node) {
+            if !search.costs.contains_key(&neighbor) {
+                search.costs.insert(neighbor.clone(), cost);
+                search.parents.insert(neighbor.clone(), node.clone());
+                to_visit.push_back(neighbor);
+            }
+        }
+    }
+
+    (search, None)
+}
+
+fn best_first<N, F, I, H, G>(
+    sources: impl IntoIterator<Item = N>,
+    mut neighbors: F,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> (Search<N>, Option<N>)
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, usize)>,
+    H: FnMut(&N) -> usize,
+    G: FnMut(&N) -> bool,
+{
+    let mut search = Search::new();
+    // nodes don't need to be `Ord`: the heap only holds indexes into `queued`
+    let mut queued: Vec<(N, usize)> = vec![];
+    let mut heap = BinaryHeap::new();
+
+    for source in sources {
+        if search.costs.insert(source.clone(), 0).is_none() {
+            heap.push(Reverse((heuristic(&source), queued.len())));
+            queued.push((source, 0));
+        }
+    }
+
+    while let Some(Reverse((_, index))) = heap.pop() {
+        let (node, cost) = queued[index].clone();
+        if search.costs[&node] < cost {
+            // a cheaper way to this node was found after it was queued
+            continue;
+        }
+        if is_goal(&node) {
+            return (search, Some(node));
+        }
+
+        for (neighbor, step_cost) in neighbors(&node) {
+            let new_cost = cost + step_cost;
+            if search
+                .costs
+                .get(&neighbor)
+                .is_none_or(|known| new_cost < *known)
+            {
+                search.costs.insert(neighbor.clone(), new_cost);
+                search.parents.insert(neighbor.clone(), node.clone());
+                heap.push(Reverse((new_cost + heuristic(&neighbor), queued.len())));
+                queued.push((neighbor, new_cost));
+            }
+        }
+    }
+
+    (search, None)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    Four,
+    Eight,
+}
+
+impl Connectivity {
+    pub fn offsets(self) -> &'static [(isize, isize)] {
+        match self {
+            Connectivity::Four => &[(-1, 0), (0, -1), (0, 1), (1, 0)],
+            Connectivity::Eight => &[
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+                (0, -1),
+                (0, 1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+            ],
+        }
+    }
+
+    // Fewest steps between two cells on an empty grid
+    pub fn distance(self, (a_row, a_col): Cell, (b_row, b_col): Cell) -> usize {
+        let rows = a_row.abs_diff(b_row);
+        let cols = a_col.abs_diff(b_col);
+        match self {
+            Connectivity::Four => rows + cols,
+            Connectivity::Eight => rows.max(cols),
+        }
+    }
+}
+
+pub fn grid_neighbors<T>(
+    matrix: &Matrix2D<T>,
+    (row, col): Cell,
+    connectivity: Connectivity,
+) -> impl Iterator<Item = Cell> + '_ {
+    connectivity
+        .offsets()
+        .iter()
+        .filter_map(move |(row_offset, col_offset)| {
+            let row = row.checked_add_signed(*row_offset)?;
+            let col = col.checked_add_signed(*col_offset)?;
+            (row < matrix.rows() && col < matrix.cols()).then_some((row, col))
+        })
+}
+
+// `can_move` receives the values of the current cell and of the neighbour
+pub fn grid_bfs<T, F>(
+    matrix: &Matrix2D<T>,
+    connectivity: Connectivity,
+    sources: impl IntoIterator<Item = Cell>,
+    can_move: F,
+) -> Search<Cell>
+where
+    F: Fn(&T, &T) -> bool,
+{
+    bfs(sources, |cell| {
+        let from = &matrix[*cell];
+        grid_neighbors(matrix, *cell, connectivity)
+            .filter(|neighbor| can_move(from, &matrix[*neighbor]))
+            .collect::<Vec<_>>()
+    })
+}
+
+// `cost` returns `None` when the move isn't allowed
+pub fn grid_dijkstra<T, F>(
+    matrix: &Matrix2D<T>,
+    connectivity: Connectivity,
+    sources: impl IntoIterator<Item = Cell>,
+    cost: F,
+) -> Search<Cell>
+where
+    F: Fn(&T, &T) -> Option<usize>,
+{
+    dijkstra(sources, grid_costs(matrix, connectivity, cost))
+}
+
+// The heuristic is the number of steps to the goal on an empty grid, so every
+// move must cost at least 1
+pub fn grid_astar<T, F>(
+    matrix: &Matrix2D<T>,
+    connectivity: Connectivity,
+    sources: impl IntoIterator<Item = Cell>,
+    goal: Cell,
+    cost: F,
+) -> Option<Path<Cell>>
+where
+    F: Fn(&T, &T) -> Option<usize>,
+{
+    astar(
+        sources,
+        grid_costs(matrix, connectivity, cost),
+        |cell| connectivity.distance(*cell, goal),
+        |cell| *cell == goal,
+    )
+}
+
+fn grid_costs<'a, T, F>(
+    matrix: &'a Matrix2D<T>,
+    connectivity: Connectivity,
+    cost: F,
+) -> impl FnMut(&Cell) -> Vec<(Cell, usize)> + 'a
+where
+    F: Fn(&T, &T) -> Option<usize> + 'a,
+{
+    move |cell| {
+        let from = &matrix[*cell];
+        grid_neighbors(matrix, *cell, connectivity)
+            .filter_map(|neighbor| cost(from, &matrix[neighbor]).map(|cost| (neighbor, cost)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // '#' are walls, digits are the cost of entering a cell
+    fn maze() -> Matrix2D<char> {
+        "S.5.\n.#..\n..#.\n#...".into()
+    }
+
+    fn open(_: &char, to: &char) -> bool {
+        *to != '#'
+    }
+
+    fn weighted(_: &char, to: &char) -> Option<usize> {
+        match to {
+            '#' => None,
+            c => Some(c.to_digit(10).unwrap_or(1) as usize),
+        }
+    }
+
+    #[test]
+    fn test_grid_neighbors() {
+        let matrix = maze();
+        assert_eq!(
+            grid_neighbors(&matrix, (0, 0), Connectivity::Four).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(
+            grid_neighbors(&matrix, (1, 1), Connectivity::Eight).count(),
+            8
+        );
+        assert_eq!(
+            grid_neighbors(&matrix, (3, 3), Connectivity::Eight).count(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_grid_bfs() {
+        let matrix = maze();
+        let search = grid_bfs(&matrix, Connectivity::Four, [(0, 0)], open);
+
+        assert_eq!(search.cost(&(3, 3)), Some(6));
+        assert_eq!(search.cost(&(1, 1)), None);
+
+        let path = search.path_to(&(3, 3)).unwrap();
+        assert_eq!(path.steps(), 6);
+        assert_eq!(path.start(), Some(&(0, 0)));
+        assert_eq!(path.end(), Some(&(3, 3)));
+        assert!(path.nodes.windows(2).all(|step| {
+            Connectivity::Four.distance(step[0], step[1]) == 1 && matrix[step[1]] != '#'
+        }));
+    }
+
+    #[test]
+    fn test_grid_bfs_eight_connectivity() {
+        let matrix = maze();
+        let search = grid_bfs(&matrix, Connectivity::Eight, [(0, 0)], open);
+        assert_eq!(search.cost(&(3, 3)), Some(4));
+    }
+
+    #[test]
+    fn test_multiple_sources() {
+        let matrix = maze();
+        let search = grid_bfs(&matrix, Connectivity::Four, [(0, 0), (3, 3)], open);
+
+        assert_eq!(search.cost(&(3, 3)), Some(0));
+        assert_eq!(search.cost(&(0, 3)), Some(3));
+        assert_eq!(search.path_to(&(1, 0)).unwrap().start(), Some(&(0, 0)));
+    }
+
+    #[test]
+    fn test_grid_dijkstra_avoids_expensive_cells() {
+        let matrix = maze();
+        let search = grid_dijkstra(&matrix, Connectivity::Four, [(0, 0)], weighted);
+
+        // around the left side, instead of through the 5
+        assert_eq!(search.cost(&(2, 3)), Some(7));
+        assert!(!search.path_to(&(2, 3)).unwrap().nodes.contains(&(0, 2)));
+    }
+
+    #[test]
+    fn test_grid_astar_matches_dijkstra() {
+        let matrix = maze();
+        let search = grid_dijkstra(&matrix, Connectivity::Four, [(0, 0)], weighted);
+
+        for (cell, cost) in search.reached() {
+            let path = grid_astar(&matrix, Connectivity::Four, [(0, 0)], *cell, weighted);
+            assert_eq!(path.map(|path| path.cost), Some(cost));
+        }
+        assert_eq!(
+            grid_astar(&matrix, Connectivity::Four, [(0, 0)], (1, 1), weighted),
+            None
+        );
+    }
+
+    #[test]
+    fn test_neighbor_function_graph() {
+        // numbers, moving to n + 1 or n * 2
+        let next = |n: &u32| [n + 1, n * 2].into_iter().filter(|n| *n <= 100);
+
+        let path = bfs_to([1], next, |n| *n == 100).unwrap();
+        assert_eq!(path.steps(), 8);
+        assert_eq!(path.end(), Some(&100));
+
+        let weighted = |n: &u32| {
+            let n = *n;
+            next(&n).map(move |m| (m, if m == n + 1 { 1 } else { 5 }))
+        };
+        let path = dijkstra_to([1], weighted, |n| *n == 8).unwrap();
+        assert_eq!(path.cost, 7);
+        assert_eq!(
+            astar(
+                [1],
+                weighted,
+                |n| 8u32.saturating_sub(*n) as usize,
+                |n| *n == 8
+            ),
+            Some(path)
+        );
+    }
+}
@@ -1,3 +1,7 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::Direction;
+
 #[derive(Hash, PartialEq, Eq, Debug, Clone, Copy)]
 pub struct Point<T> {
     pub x: T,
@@ -10,6 +14,52 @@ impl<T> Point<T> {
     }
 }
 
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self {
+        Self::new(self.x * scalar, self.y * scalar)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
 macro_rules! impl_ints_methods {
     ($($t:ty),*) => {
         $(
@@ -51,16 +101,61 @@ macro_rules! impl_ints_methods {
             }
 
             pub fn cab_distance(&self, other: &Self) -> usize {
-                let x = (self.x as isize - other.x as isize).abs() as usize;
-                let y = (self.y as isize - other.y as isize).abs() as usize;
+                let x = (self.x as isize - other.x as isize).unsigned_abs();
+                let y = (self.y as isize - other.y as isize).unsigned_abs();
                 x + y
             }
+
+            pub fn chebyshev_distance(&self, other: &Self) -> usize {
+                let x = (self.x as isize - other.x as isize).unsigned_abs();
+                let y = (self.y as isize - other.y as isize).unsigned_abs();
+                x.max(y)
+            }
         })*
     };
 }
 
 impl_ints_methods!(isize, i32, i64, usize, u32, u64);
 
+macro_rules! impl_signed_methods {
+    ($($t:ty),*) => {
+        $(
+        impl Add<Direction> for Point<$t> {
+            type Output = Self;
+
+            fn add(self, direction: Direction) -> Self {
+                let unit = direction.unit();
+                Self::new(self.x + unit.x as $t, self.y + unit.y as $t)
+            }
+        }
+
+        impl AddAssign<Direction> for Point<$t> {
+            fn add_assign(&mut self, direction: Direction) {
+                *self = *self + direction;
+            }
+        }
+
+        impl Point<$t> {
+            // a step of at most one in each axis, towards the same quadrant
+            pub fn signum(&self) -> Self {
+                Self::new(self.x.signum(), self.y.signum())
+            }
+
+            pub fn neighbors4(&self) -> impl Iterator<Item = Self> {
+                let point = *self;
+                Direction::ORTHOGONAL.into_iter().map(move |d| point + d)
+            }
+
+            pub fn neighbors8(&self) -> impl Iterator<Item = Self> {
+                let point = *self;
+                Direction::ALL.into_iter().map(move |d| point + d)
+            }
+        })*
+    };
+}
+
+impl_signed_methods!(isize, i32, i64);
+
 macro_rules! impl_float_methods {
     ($($t:ty),*) => {
         $(impl Point<$t> {
@@ -87,6 +182,41 @@ mod tests {
         assert_eq!(a.euclidean_distance(&b), 5.0);
     }
 
+    #[test]
+    fn test_operators() {
+        let mut a: Point<isize> = Point::new(1, 2);
+        let b: Point<isize> = Point::new(3, -4);
+
+        assert_eq!(a + b, Point::new(4, -2));
+        assert_eq!(a - b, Point::new(-2, 6));
+        assert_eq!(-a, Point::new(-1, -2));
+        assert_eq!(b * 2, Point::new(6, -8));
+
+        a += b;
+        assert_eq!(a, Point::new(4, -2));
+        a -= b;
+        assert_eq!(a, Point::new(1, 2));
+    }
+
+    #[test]
+    fn test_directions() {
+        let mut a: Point<i32> = Point::new(0, 0);
+        assert_eq!(a + Direction::UpLeft, a.above_left());
+        assert_eq!(a + Direction::Down, a.below());
+
+        a += Direction::Right;
+        assert_eq!(a, Point::new(1, 0));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let a: Point<isize> = Point::new(5, 5);
+        let neighbors4 = a.neighbors4().collect::<Vec<_>>();
+        assert_eq!(neighbors4, vec![a.above(), a.right(), a.below(), a.left()]);
+        assert_eq!(a.neighbors8().count(), 8);
+        assert!(a.neighbors8().all(|n| n != a && a.cab_distance(&n) <= 2));
+    }
+
     #[test]
     fn test_cab_distance_between_two_points() {
         let a: Point<isize> = Point::new(0, 0);
@@ -94,4 +224,20 @@ mod tests {
 
         assert_eq!(a.cab_distance(&b), 7);
     }
+
+    #[test]
+    fn test_chebyshev_distance_between_two_points() {
+        let a: Point<isize> = Point::new(0, 0);
+
+        assert_eq!(a.chebyshev_distance(&Point::new(3, -4)), 4);
+        assert_eq!(a.chebyshev_distance(&Point::new(1, 1)), 1);
+        assert_eq!(a.chebyshev_distance(&a), 0);
+    }
+
+    #[test]
+    fn test_signum() {
+        let a: Point<isize> = Point::new(-7, 3);
+        assert_eq!(a.signum(), Point::new(-1, 1));
+        assert_eq!(Point::new(0, -2isize).signum(), Point::new(0, -1));
+    }
 }
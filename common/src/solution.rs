@@ -0,0 +1,191 @@
+use std::any::Any;
+use std::fmt::Display;
+
+use crate::error::{AocError, AocResult};
+
+pub type PartResult<T> = AocResult<T>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+pub trait Solution {
+    type Input: 'static;
+    type Output1: Display;
+    type Output2: Display;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input>;
+    fn part_1(&self, input: &Self::Input) -> PartResult<Self::Output1>;
+    fn part_2(&self, input: &Self::Input) -> PartResult<Self::Output2>;
+
+    // Other implementations of the parts, which can be benchmarked against
+    // `part_1` and `part_2`
+    fn alternatives(&self) -> Vec<Alternative<Self>> {
+        Vec::new()
+    }
+}
+
+pub struct Alternative<S: Solution + ?Sized> {
+    pub name: &'static str,
+    pub part: Part,
+    pub solve: fn(&S, &S::Input) -> PartResult<String>,
+}
+
+impl<S: Solution + ?Sized> Alternative<S> {
+    pub fn new(
+        name: &'static str,
+        part: Part,
+        solve: fn(&S, &S::Input) -> PartResult<String>,
+    ) -> Self {
+        Self { name, part, solve }
+    }
+}
+
+// Type-erased view of a `Solution`, so that days with different input types
+// can live in the same collection
+pub trait DynSolution {
+    fn parse_boxed(&self, input: &str) -> AocResult<Box<dyn Any>>;
+    fn solve(&self, part: Part, input: &dyn Any) -> PartResult<String>;
+    fn alternatives(&self) -> Vec<(&'static str, Part)>;
+    fn solve_alternative(&self, name: &str, part: Part, input: &dyn Any) -> PartResult<String>;
+}
+
+impl<S: Solution> DynSolution for S {
+    fn parse_boxed(&self, input: &str) -> AocResult<Box<dyn Any>> {
+        Ok(Box::new(self.parse(input)?))
+    }
+
+    fn solve(&self, part: Part, input: &dyn Any) -> PartResult<String> {
+        let input = downcast_input::<S>(input)?;
+
+        match part {
+            Part::One => Ok(self.part_1(input)?.to_string()),
+            Part::Two => Ok(self.part_2(input)?.to_string()),
+        }
+    }
+
+    fn alternatives(&self) -> Vec<(&'static str, Part)> {
+        Solution::alternatives(self)
+            .into_iter()
+            .map(|alternative| (alternative.name, alternative.part))
+            .collect()
+    }
+
+    fn solve_alternative(&self, name: &str, part: Part, input: &dyn Any) -> PartResult<String> {
+        let input = downcast_input::<S>(input)?;
+        let alternative = Solution::alternatives(self)
+            .into_iter()
+            .find(|alternative| alternative.name == name && alternative.part == part)
+            .ok_or_else(|| {
+                AocError::invalid_state(format!("Unknown alternative {} for part {}", name, part))
+            })?;
+
+        (alternative.solve)(self, input)
+    }
+}
+
+fn downcast_input<S: Solution>(input: &dyn Any) -> AocResult<&S::Input> {
+    input
+        .downcast_ref::<S::Input>()
+        .ok_or_else(|| AocError::invalid_state("Input was parsed by a different solution"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    struct Number(u64);
+
+    impl std::str::FromStr for Number {
+        type Err = AocError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Ok(Number(s.parse()?))
+        }
+    }
+
+    impl Solution for Sum {
+        type Input = Vec<Number>;
+        type Output1 = u64;
+        type Output2 = usize;
+
+        fn parse(&self, input: &str) -> AocResult<Self::Input> {
+            crate::error::parse_lines(input)
+        }
+
+        fn part_1(&self, input: &Self::Input) -> PartResult<u64> {
+            Ok(input.iter().map(|n| n.0).sum())
+        }
+
+        fn part_2(&self, input: &Self::Input) -> PartResult<usize> {
+            Ok(input.len())
+        }
+
+        fn alternatives(&self) -> Vec<Alternative<Self>> {
+            vec![Alternative::new(
+                "fold",
+                Part::One,
+                |_, input: &Vec<Number>| Ok(input.iter().fold(0, |sum, n| sum + n.0).to_string()),
+            )]
+        }
+    }
+
+    #[test]
+    fn test_dyn_solution_shares_parsed_input() {
+        let solution: &dyn DynSolution = &Sum;
+        let input = solution.parse_boxed("1\n2\n3").unwrap();
+
+        assert_eq!(solution.solve(Part::One, input.as_ref()).unwrap(), "6");
+        assert_eq!(solution.solve(Part::Two, input.as_ref()).unwrap(), "3");
+    }
+
+    #[test]
+    fn test_dyn_solution_alternatives() {
+        let solution: &dyn DynSolution = &Sum;
+        let input = solution.parse_boxed("1\n2\n3").unwrap();
+
+        assert_eq!(solution.alternatives(), vec![("fold", Part::One)]);
+        assert_eq!(
+            solution
+                .solve_alternative("fold", Part::One, input.as_ref())
+                .unwrap(),
+            "6"
+        );
+        assert!(solution
+            .solve_alternative("fold", Part::Two, input.as_ref())
+            .is_err());
+        assert!(solution
+            .solve_alternative("nope", Part::One, input.as_ref())
+            .is_err());
+    }
+
+    #[test]
+    fn test_dyn_solution_reports_parse_errors() {
+        let solution: &dyn DynSolution = &Sum;
+        assert!(solution.parse_boxed("1\nx").is_err());
+    }
+
+    #[test]
+    fn test_dyn_solution_rejects_foreign_input() {
+        let solution: &dyn DynSolution = &Sum;
+        let input: Box<dyn Any> = Box::new("not a vec");
+        assert!(solution.solve(Part::One, input.as_ref()).is_err());
+    }
+}
@@ -0,0 +1,217 @@
+use std::collections::HashMap;
+
+use crate::pathfinding::Connectivity;
+use crate::{BoundingBox, Matrix2D, Point};
+
+// Cells on an unbounded plane, where x grows to the right and y grows down
+// like the columns and rows of a `Matrix2D`
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point<isize>, T>,
+    bounds: Option<BoundingBox>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, point: &Point<isize>) -> Option<&T> {
+        self.cells.get(point)
+    }
+
+    pub fn get_mut(&mut self, point: &Point<isize>) -> Option<&mut T> {
+        self.cells.get_mut(point)
+    }
+
+    pub fn contains(&self, point: &Point<isize>) -> bool {
+        self.cells.contains_key(point)
+    }
+
+    pub fn insert(&mut self, point: Point<isize>, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            None => BoundingBox::new(point, point),
+            Some(bounds) => bounds.include(point),
+        });
+        self.cells.insert(point, value)
+    }
+
+    pub fn remove(&mut self, point: &Point<isize>) -> Option<T> {
+        let value = self.cells.remove(point)?;
+        // the bounds only need to be scanned again when an edge cell goes away
+        if let Some(BoundingBox { min, max }) = self.bounds {
+            if point.x == min.x || point.x == max.x || point.y == min.y || point.y == max.y {
+                self.bounds = BoundingBox::from_points(self.cells.keys().copied());
+            }
+        }
+        Some(value)
+    }
+
+    pub fn bounds(&self) -> Option<BoundingBox> {
+        self.bounds
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Point<isize>, &T)> {
+        self.cells.iter()
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = &Point<isize>> {
+        self.cells.keys()
+    }
+
+    // Occupied cells next to `point`
+    pub fn neighbors(
+        &self,
+        point: Point<isize>,
+        connectivity: Connectivity,
+    ) -> impl Iterator<Item = (Point<isize>, &T)> {
+        connectivity.offsets().iter().filter_map(move |(dy, dx)| {
+            let neighbor = point.shift(*dx, *dy);
+            self.get(&neighbor).map(|value| (neighbor, value))
+        })
+    }
+
+    // Every point of `area`, row by row
+    pub fn region(&self, area: BoundingBox) -> impl Iterator<Item = (Point<isize>, Option<&T>)> {
+        area.points().map(|point| (point, self.get(&point)))
+    }
+
+    // Takes the cells of `matrix` for which `keep` is true, with the top left
+    // corner of the matrix placed at `offset`
+    pub fn from_matrix<F>(matrix: Matrix2D<T>, offset: Point<isize>, keep: F) -> Self
+    where
+        F: Fn(&T) -> bool,
+    {
+        let cols = matrix.cols();
+        matrix
+            .into_iter()
+            .enumerate()
+            .filter(|(_, value)| keep(value))
+            .map(|(index, value)| {
+                let point = offset.shift((index % cols) as isize, (index / cols) as isize);
+                (point, value)
+            })
+            .collect()
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    // A dense copy of the bounds, with `empty` in the missing cells. The
+    // returned offset is the point that ended up at the top left corner.
+    pub fn to_matrix(&self, empty: T) -> Option<(Matrix2D<T>, Point<isize>)> {
+        let bounds = self.bounds?;
+        let min = bounds.min;
+
+        let mut data = vec![vec![empty; bounds.width()]; bounds.height()];
+        for (point, value) in &self.cells {
+            data[(point.y - min.y) as usize][(point.x - min.x) as usize] = value.clone();
+        }
+
+        Some((data.into(), min))
+    }
+}
+
+impl<T> FromIterator<(Point<isize>, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point<isize>, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T> Extend<(Point<isize>, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (Point<isize>, T)>>(&mut self, iter: I) {
+        for (point, value) in iter {
+            self.insert(point, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> SparseGrid<char> {
+        [(-2, 1, 'a'), (3, -1, 'b'), (0, 0, 'c'), (1, 0, 'd')]
+            .into_iter()
+            .map(|(x, y, c)| (Point::new(x, y), c))
+            .collect()
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = grid();
+        let bounds = |a, b| Some(BoundingBox::new(a, b));
+        assert_eq!(grid.bounds(), bounds(Point::new(-2, -1), Point::new(3, 1)));
+
+        grid.remove(&Point::new(0, 0));
+        assert_eq!(grid.bounds(), bounds(Point::new(-2, -1), Point::new(3, 1)));
+
+        grid.remove(&Point::new(-2, 1));
+        assert_eq!(grid.bounds(), bounds(Point::new(1, -1), Point::new(3, 0)));
+
+        grid.remove(&Point::new(3, -1));
+        grid.remove(&Point::new(1, 0));
+        assert_eq!(grid.bounds(), None);
+        assert!(grid.is_empty());
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = grid();
+        let mut neighbors = grid
+            .neighbors(Point::new(0, 1), Connectivity::Four)
+            .map(|(_, c)| *c)
+            .collect::<Vec<_>>();
+        neighbors.sort();
+        assert_eq!(neighbors, vec!['c']);
+
+        let mut neighbors = grid
+            .neighbors(Point::new(0, 1), Connectivity::Eight)
+            .map(|(_, c)| *c)
+            .collect::<Vec<_>>();
+        neighbors.sort();
+        assert_eq!(neighbors, vec!['c', 'd']);
+    }
+
+    #[test]
+    fn test_region() {
+        let grid = grid();
+        let region = grid
+            .region(BoundingBox::new(Point::new(-1, 0), Point::new(1, 1)))
+            .map(|(_, c)| c.copied().unwrap_or('.'))
+            .collect::<String>();
+        assert_eq!(region, ".cd...");
+    }
+
+    #[test]
+    fn test_matrix_round_trip() {
+        let grid = grid();
+        let (matrix, offset) = grid.to_matrix('.').unwrap();
+        assert_eq!(offset, Point::new(-2, -1));
+        assert_eq!(matrix.to_string(), ".....b\n..cd..\na.....\n");
+
+        let back = SparseGrid::from_matrix(matrix, offset, |c| *c != '.');
+        assert_eq!(back.len(), 4);
+        assert_eq!(back.get(&Point::new(-2, 1)), Some(&'a'));
+        assert_eq!(back.get(&Point::new(1, 0)), Some(&'d'));
+        assert_eq!(back.bounds(), grid.bounds());
+    }
+}
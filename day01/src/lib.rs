@@ -0,0 +1,77 @@
+use common::{AocError, AocResult, PartResult, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<u64>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        extract_elves(input.lines())
+    }
+
+    fn part_1(&self, elves_values: &Self::Input) -> PartResult<u64> {
+        Ok(*elves_values
+            .first()
+            .ok_or_else(|| AocError::no_solution("No elves found"))?)
+    }
+
+    fn part_2(&self, elves_values: &Self::Input) -> PartResult<u64> {
+        Ok(elves_values.iter().take(3).sum())
+    }
+}
+
+fn extract_elves<'a>(input: impl Iterator<Item = &'a str>) -> AocResult<Vec<u64>> {
+    let mut elves = vec![];
+    let mut current = 0;
+    for (index, input_line) in input.enumerate() {
+        if input_line.is_empty() {
+            elves.push(current);
+            current = 0;
+        } else {
+            current += input_line
+                .parse::<u64>()
+                .map_err(|e| AocError::from(e).at_line(index + 1))?;
+        }
+    }
+
+    elves.push(current);
+    elves.sort_by(|a, b| b.cmp(a));
+
+    Ok(elves)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_single_elf() {
+        let input = "1000\n2000\n3000";
+        let expected = vec![6000];
+
+        assert_eq!(extract_elves(input.lines()).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_extract_multiple_elves() {
+        let input = "1000\n2000\n\n3000\n4000\n5000";
+        let expected = vec![12000, 3000];
+
+        assert_eq!(extract_elves(input.lines()).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_extract_invalid_calories() {
+        let input = "1000
+
+2o00";
+        let error = extract_elves(input.lines()).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Parse error at line 3: invalid digit found in string"
+        );
+    }
+}
@@ -0,0 +1,177 @@
+use std::str::FromStr;
+
+use common::{AocError, AocResult, PartResult, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Round>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, rounds: &Self::Input) -> PartResult<u64> {
+        Ok(calculate(rounds, BaseParser))
+    }
+
+    fn part_2(&self, rounds: &Self::Input) -> PartResult<u64> {
+        Ok(calculate(rounds, ExtendedParser))
+    }
+}
+
+trait Parser {
+    fn parse(&self, round: Round) -> (u8, u8);
+}
+
+// Rock 0
+// Paper 1
+// Scissor 2
+
+// Both columns as indexes in 0..3: the opponent's A/B/C and our X/Y/Z
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round(u8, u8);
+
+impl FromStr for Round {
+    type Err = AocError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let column = |index: usize, first: u8| {
+            line.as_bytes()
+                .get(index)
+                .map(|value| value.wrapping_sub(first))
+                .filter(|value| *value < 3)
+                .ok_or_else(|| {
+                    AocError::parse(format!(
+                        "Expected one of {}",
+                        (first..first + 3).map(char::from).collect::<String>()
+                    ))
+                    .at_column(index + 1)
+                })
+        };
+
+        if line.len() != 3 || line.as_bytes()[1] != b' ' {
+            return Err(AocError::parse(format!("Invalid round '{}'", line)));
+        }
+
+        Ok(Round(column(0, b'A')?, column(2, b'X')?))
+    }
+}
+
+struct BaseParser;
+struct ExtendedParser;
+
+impl Parser for BaseParser {
+    fn parse(&self, Round(first_value, second_value): Round) -> (u8, u8) {
+        (first_value, second_value)
+    }
+}
+
+impl Parser for ExtendedParser {
+    fn parse(&self, Round(first_value, second_value): Round) -> (u8, u8) {
+        // X loses, Y draws and Z wins
+        (first_value, (first_value + second_value + 2) % 3)
+    }
+}
+
+fn outcome(p1: u8, p2: u8) -> u64 {
+    if (p1 + 1) % 3 == p2 {
+        // win
+        7 + p2 as u64
+    } else if p1 == p2 {
+        // even
+        4 + p2 as u64
+    } else {
+        // loss
+        1 + p2 as u64
+    }
+}
+
+fn parse_input(input: &str) -> AocResult<Vec<Round>> {
+    common::error::parse_lines(input)
+}
+
+fn calculate(rounds: &[Round], parser: impl Parser) -> u64 {
+    rounds
+        .iter()
+        .map(|round| parser.parse(*round))
+        .map(|(a, b)| outcome(a, b))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_round(line: &str) -> Round {
+        line.parse().unwrap()
+    }
+
+    #[test]
+    fn test_outcome() {
+        assert_eq!(outcome(0, 1), 8);
+        assert_eq!(outcome(1, 0), 1);
+        assert_eq!(outcome(2, 2), 6);
+    }
+
+    #[test]
+    fn test_base_parser() {
+        assert_eq!(BaseParser.parse(parse_round("A X")), (0, 0));
+        assert_eq!(BaseParser.parse(parse_round("B Y")), (1, 1));
+        assert_eq!(BaseParser.parse(parse_round("C Z")), (2, 2));
+    }
+
+    #[test]
+    fn test_extended_parser() {
+        assert_eq!(ExtendedParser.parse(parse_round("A X")), (0, 2));
+        assert_eq!(ExtendedParser.parse(parse_round("B X")), (1, 0));
+        assert_eq!(ExtendedParser.parse(parse_round("C X")), (2, 1));
+
+        assert_eq!(ExtendedParser.parse(parse_round("A Y")), (0, 0));
+        assert_eq!(ExtendedParser.parse(parse_round("B Y")), (1, 1));
+        assert_eq!(ExtendedParser.parse(parse_round("C Y")), (2, 2));
+
+        assert_eq!(ExtendedParser.parse(parse_round("A Z")), (0, 1));
+        assert_eq!(ExtendedParser.parse(parse_round("B Z")), (1, 2));
+        assert_eq!(ExtendedParser.parse(parse_round("C Z")), (2, 0));
+    }
+
+    #[test]
+    fn test_parse_outcome() {
+        let (a, b) = ExtendedParser.parse(parse_round("A Y"));
+        assert_eq!(outcome(a, b), 4);
+
+        let (a, b) = ExtendedParser.parse(parse_round("B X"));
+        assert_eq!(outcome(a, b), 1);
+
+        let (a, b) = ExtendedParser.parse(parse_round("C Z"));
+        assert_eq!(outcome(a, b), 7)
+    }
+
+    #[test]
+    fn test_input_base() {
+        assert_eq!(
+            calculate(&parse_input("A Y\nB X\nC Z\n").unwrap(), BaseParser),
+            15
+        )
+    }
+
+    #[test]
+    fn test_input_extended() {
+        assert_eq!(
+            calculate(&parse_input("A Y\nB X\nC Z\n").unwrap(), ExtendedParser),
+            12
+        )
+    }
+
+    #[test]
+    fn test_invalid_round() {
+        assert_eq!(
+            parse_input("A Y\nB W").unwrap_err().to_string(),
+            "Parse error at 2:3: Expected one of XYZ"
+        );
+        assert!(parse_input("A Y\nAY").is_err());
+    }
+}
@@ -0,0 +1,149 @@
+use std::collections::HashSet;
+
+use common::{AocError, AocResult, PartResult, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| parse_rucksack(line).map_err(|e| e.at_line(index + 1)))
+            .collect()
+    }
+
+    fn part_1(&self, rucksacks: &Self::Input) -> PartResult<u64> {
+        part_1(rucksacks)
+    }
+
+    fn part_2(&self, rucksacks: &Self::Input) -> PartResult<u64> {
+        part_2(rucksacks)
+    }
+}
+
+fn parse_rucksack(line: &str) -> AocResult<String> {
+    if let Some(index) = line.chars().position(|c| !c.is_ascii_alphabetic()) {
+        return Err(AocError::parse("Items must be letters").at_column(index + 1));
+    }
+    if !line.len().is_multiple_of(2) {
+        return Err(AocError::parse(
+            "Rucksack can't be split in two equal compartments",
+        ));
+    }
+
+    Ok(line.to_string())
+}
+
+fn no_common_item() -> AocError {
+    AocError::no_solution("Nothing in common?")
+}
+
+fn part_1(rucksacks: &[String]) -> AocResult<u64> {
+    rucksacks
+        .iter()
+        .map(String::as_str)
+        // split each line in half
+        .map(split_compartments)
+        // check which char is in common between the two
+        .map(|(a, b)| find_common(&[a, b]).ok_or_else(no_common_item))
+        // convert the common char with priority
+        .map(|common| common.map(char_priority))
+        // sum it all
+        .sum::<AocResult<u64>>()
+}
+
+fn part_2(rucksacks: &[String]) -> AocResult<u64> {
+    rucksacks
+        // chunk in groups of three
+        .chunks(3)
+        // find the common element between group
+        .map(|group| {
+            let group: Vec<&str> = group.iter().map(String::as_str).collect();
+            find_common(&group).ok_or_else(no_common_item)
+        })
+        // convert the common char with priority
+        .map(|common| common.map(char_priority))
+        // sum it all
+        .sum::<AocResult<u64>>()
+}
+
+fn split_compartments(rucksack: &str) -> (&str, &str) {
+    rucksack.split_at(rucksack.len() / 2)
+}
+
+fn find_common(input: &[&str]) -> Option<char> {
+    // transform each input in an hashset containing the chars
+    let hashsets: Vec<HashSet<char>> = input.iter().map(|comp| comp.chars().collect()).collect();
+
+    // find which element is common between all hashsets
+    hashsets
+        .into_iter()
+        .reduce(|a, b| a.intersection(&b).cloned().collect())
+        .and_then(|hash| hash.iter().next().copied())
+}
+
+fn char_priority(c: char) -> u64 {
+    match c {
+        'a'..='z' => c as u64 - 'a' as u64 + 1,
+        'A'..='Z' => c as u64 - 'A' as u64 + 27,
+        _ => unreachable!("Rucksacks are validated to only hold letters"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! test_find_common {
+        ($f:ident, $a:expr, $b:expr, $expected:expr) => {
+            #[test]
+            fn $f() {
+                let result = find_common(&[$a, $b]).expect("Not found");
+                assert_eq!(result, $expected)
+            }
+        };
+    }
+
+    #[test]
+    fn test_split_compartment() {
+        let rucksack = "vJrwpWtwJgWrhcsFMMfFFhFp";
+        let (a, b) = split_compartments(rucksack);
+        assert_eq!(a, "vJrwpWtwJgWr");
+        assert_eq!(b, "hcsFMMfFFhFp");
+    }
+
+    test_find_common!(common_1, "vJrwpWtwJgWr", "hcsFMMfFFhFp", 'p');
+    test_find_common!(common_2, "jqHRNqRjqzjGDLGL", "rsFMfFZSrLrFZsSL", 'L');
+    test_find_common!(common_3, "PmmdzqPrV", "vPwwTWBwg", 'P');
+    test_find_common!(common_4, "wMqvLMZHhHMvwLH", "jbvcjnnSBnvTQFn", 'v');
+    test_find_common!(common_5, "ttgJtRGJ", "QctTZtZT", 't');
+    test_find_common!(common_6, "CrZsJsPPZsGz", "wwsLwLmpwMDw", 's');
+
+    #[test]
+    fn test_parse_invalid_rucksack() {
+        assert_eq!(
+            Day03.parse("vJrwpWtwJgWr\nvJr wp").unwrap_err().to_string(),
+            "Parse error at 2:4: Items must be letters"
+        );
+        assert!(Day03.parse("abc").is_err());
+    }
+
+    #[test]
+    fn test_char_priority() {
+        assert_eq!(char_priority('a'), 1);
+        assert_eq!(char_priority('z'), 26);
+        assert_eq!(char_priority('A'), 27);
+        assert_eq!(char_priority('Z'), 52);
+        assert_eq!(char_priority('p'), 16);
+        assert_eq!(char_priority('L'), 38);
+        assert_eq!(char_priority('P'), 42);
+        assert_eq!(char_priority('v'), 22);
+        assert_eq!(char_priority('t'), 20);
+        assert_eq!(char_priority('s'), 19);
+    }
+}
@@ -0,0 +1,137 @@
+use std::ops::RangeInclusive;
+
+use common::{intervals, AocError, AocResult, PartResult, Solution};
+
+type Pair = (RangeInclusive<u64>, RangeInclusive<u64>);
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Pair>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, pairs: &Self::Input) -> PartResult<usize> {
+        Ok(part_1(pairs))
+    }
+
+    fn part_2(&self, pairs: &Self::Input) -> PartResult<usize> {
+        Ok(part_2(pairs))
+    }
+}
+
+fn overlapping(first: &RangeInclusive<u64>, second: &RangeInclusive<u64>) -> bool {
+    intervals::overlaps(first, second)
+}
+
+fn superset(first: &RangeInclusive<u64>, second: &RangeInclusive<u64>) -> bool {
+    intervals::covers(first, second)
+}
+
+fn parse_input(input: &str) -> AocResult<Vec<Pair>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_pair(line).map_err(|e| e.at_line(index + 1)))
+        .collect()
+}
+
+fn parse_pair(line: &str) -> AocResult<Pair> {
+    let (a, b) = line
+        .split_once(',')
+        .ok_or_else(|| AocError::parse("Expected two comma separated ranges"))?;
+    Ok((
+        parse_range(a)?,
+        parse_range(b).map_err(|e| e.at_column(a.len() + 2))?,
+    ))
+}
+
+fn parse_range(input: &str) -> AocResult<RangeInclusive<u64>> {
+    let (a, b) = input
+        .split_once('-')
+        .ok_or_else(|| AocError::parse(format!("Invalid range '{}'", input)))?;
+    Ok(RangeInclusive::new(a.parse()?, b.parse()?))
+}
+
+fn part_1(pairs: &[Pair]) -> usize {
+    pairs
+        .iter()
+        .filter(|(a, b)| superset(a, b) || superset(b, a))
+        .count()
+}
+
+fn part_2(pairs: &[Pair]) -> usize {
+    pairs.iter().filter(|(a, b)| overlapping(a, b)).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_INPUT: &str = "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8";
+
+    macro_rules! assert_that {
+        ($a_1:literal-$a_2:literal is superset of $b_1:literal-$b_2:literal) => {
+            let a = $a_1..=$a_2;
+            let b = $b_1..=$b_2;
+            assert!(superset(&a, &b))
+        };
+        ($a_1:literal-$a_2:literal is not a superset of $b_1:literal-$b_2:literal) => {
+            let a = $a_1..=$a_2;
+            let b = $b_1..=$b_2;
+            assert!(!superset(&a, &b))
+        };
+        ($a_1:literal-$a_2:literal overlaps $b_1:literal-$b_2:literal) => {
+            let a = $a_1..=$a_2;
+            let b = $b_1..=$b_2;
+            assert!(overlapping(&a, &b))
+        };
+        ($a_1:literal-$a_2:literal does not overlap $b_1:literal-$b_2:literal) => {
+            let a = $a_1..=$a_2;
+            let b = $b_1..=$b_2;
+            assert!(!overlapping(&a, &b))
+        };
+    }
+
+    #[test]
+    fn test_superset() {
+        assert_that!(1-7 is superset of 2-5);
+        assert_that!(1-4 is not a superset of 3-6);
+    }
+
+    #[test]
+    fn test_overlapping() {
+        assert_that!(5-7 overlaps 7-9);
+        assert_that!(1-3 does not overlap 5-8);
+        assert_that!(2-3 overlaps 1-4);
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let result = parse_input(TEST_INPUT).unwrap();
+        assert_eq!(result[0], (2..=4, 6..=8));
+        assert_eq!(result[3], (2..=8, 3..=7));
+    }
+
+    #[test]
+    fn test_parse_invalid_input() {
+        assert_eq!(
+            parse_input("2-4,6-8\n2-3,45").unwrap_err().to_string(),
+            "Parse error at 2:5: Invalid range '45'"
+        );
+        assert!(parse_input("2-4;6-8").is_err());
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(&parse_input(TEST_INPUT).unwrap()), 2);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2(&parse_input(TEST_INPUT).unwrap()), 4);
+    }
+}
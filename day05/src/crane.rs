@@ -1,51 +1,176 @@
+use std::fmt::Display;
+
+use common::AocError;
+
 use crate::types::{MoveCommand, Stacks};
 
-pub fn v9000(stacks: Stacks, movements: Vec<MoveCommand>) -> Stacks {
-    let mut stacks = stacks;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Crane {
+    // moves crates one at a time
+    V9000,
+    // moves all the crates of a command at once, keeping their order
+    V9001,
+}
+
+// Why a list of moves can't be carried out. Instructions count from 1, in the
+// order they appear in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MoveError {
+    NoSuchStack {
+        instruction: usize,
+        column: usize,
+        stacks: usize,
+    },
+    NotEnoughCrates {
+        instruction: usize,
+        column: usize,
+        wanted: usize,
+        available: usize,
+    },
+}
 
-    movements.iter().for_each(|movement| {
-        step_v9000(&mut stacks, movement);
-    });
+impl Display for MoveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MoveError::NoSuchStack {
+                instruction,
+                column,
+                stacks,
+            } => write!(
+                f,
+                "Move {} uses stack {}, but there are only {} stacks",
+                instruction, column, stacks
+            ),
+            MoveError::NotEnoughCrates {
+                instruction,
+                column,
+                wanted,
+                available,
+            } => write!(
+                f,
+                "Move {} takes {} crates from stack {}, which only holds {}",
+                instruction, wanted, column, available
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MoveError {}
+
+impl From<MoveError> for AocError {
+    fn from(error: MoveError) -> Self {
+        AocError::invalid_state(error.to_string())
+    }
+}
 
-    stacks
+pub fn v9000(stacks: Stacks, movements: &[MoveCommand]) -> Result<Stacks, MoveError> {
+    run(Crane::V9000, stacks, movements)
 }
 
-pub fn v9001(stacks: Stacks, movements: Vec<MoveCommand>) -> Stacks {
+pub fn v9001(stacks: Stacks, movements: &[MoveCommand]) -> Result<Stacks, MoveError> {
+    run(Crane::V9001, stacks, movements)
+}
+
+pub fn run(crane: Crane, stacks: Stacks, movements: &[MoveCommand]) -> Result<Stacks, MoveError> {
     let mut stacks = stacks;
+    validate(&stacks, movements)?;
 
-    movements.iter().for_each(|movement| {
-        step_v9001(&mut stacks, movement);
-    });
+    for movement in movements {
+        step(crane, &mut stacks, movement);
+    }
 
-    stacks
+    Ok(stacks)
 }
 
-fn step_v9000(stacks: &mut Stacks, movement: &MoveCommand) {
-    for _ in 0..movement.amount {
-        let value = stacks[movement.from_column - 1]
-            .pop()
-            .expect("Error while taking element");
-        stacks[movement.to_column - 1].push(value);
+/// Checks every move before any crate is touched. Both cranes move the same
+/// number of crates between the same stacks, so counting them is enough.
+pub fn validate(stacks: &Stacks, movements: &[MoveCommand]) -> Result<(), MoveError> {
+    let mut heights = stacks.iter().map(Vec::len).collect::<Vec<_>>();
+
+    for (index, movement) in movements.iter().enumerate() {
+        let instruction = index + 1;
+        let stack_index = |column: usize| {
+            (1..=heights.len())
+                .contains(&column)
+                .then(|| column - 1)
+                .ok_or(MoveError::NoSuchStack {
+                    instruction,
+                    column,
+                    stacks: heights.len(),
+                })
+        };
+        let from = stack_index(movement.from_column)?;
+        let to = stack_index(movement.to_column)?;
+
+        let amount = movement.amount as usize;
+        if amount > heights[from] {
+            return Err(MoveError::NotEnoughCrates {
+                instruction,
+                column: movement.from_column,
+                wanted: amount,
+                available: heights[from],
+            });
+        }
+        heights[from] -= amount;
+        heights[to] += amount;
     }
+
+    Ok(())
 }
-fn step_v9001(stacks: &mut Stacks, movement: &MoveCommand) {
-    let mut temp_stack = vec![];
-    for _ in 0..movement.amount {
-        let value = stacks[movement.from_column - 1]
-            .pop()
-            .expect("Error while taking element");
-        temp_stack.push(value);
+
+/// The stacks after each move, once all of them have been validated
+pub fn replay(
+    crane: Crane,
+    stacks: Stacks,
+    movements: &[MoveCommand],
+) -> Result<Replay<'_>, MoveError> {
+    validate(&stacks, movements)?;
+    Ok(Replay {
+        crane,
+        stacks,
+        movements: movements.iter(),
+    })
+}
+
+pub struct Replay<'a> {
+    crane: Crane,
+    stacks: Stacks,
+    movements: std::slice::Iter<'a, MoveCommand>,
+}
+
+impl<'a> Iterator for Replay<'a> {
+    type Item = (&'a MoveCommand, Stacks);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let movement = self.movements.next()?;
+        step(self.crane, &mut self.stacks, movement);
+        Some((movement, self.stacks.clone()))
     }
+}
 
-    for _ in 0..movement.amount {
-        stacks[movement.to_column - 1].push(temp_stack.pop().unwrap());
+// The move has to be valid, see `validate`
+fn step(crane: Crane, stacks: &mut Stacks, movement: &MoveCommand) {
+    let from = movement.from_column - 1;
+    let to = movement.to_column - 1;
+    match crane {
+        // one crate at a time, so moving onto the same stack changes nothing
+        Crane::V9000 => {
+            for _ in 0..movement.amount {
+                let taken = stacks[from].pop().unwrap();
+                stacks[to].push(taken);
+            }
+        }
+        Crane::V9001 => {
+            let stack = &mut stacks[from];
+            let taken = stack.split_off(stack.len() - movement.amount as usize);
+            stacks[to].extend(taken);
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::error::Error;
 
     const TEST_INPUT: &str = r#"
     [D]
@@ -59,49 +184,118 @@ move 1 from 1 to 2
     "#;
 
     #[test]
-    fn test_step_v9000() -> Result<(), Box<dyn Error>> {
+    fn test_step_v9000() -> Result<(), AocError> {
         let (mut stacks, move_commands) = crate::parser::parse_input(TEST_INPUT)?;
 
-        step_v9000(&mut stacks, move_commands.first().unwrap());
+        step(Crane::V9000, &mut stacks, move_commands.first().unwrap());
 
-        assert_eq!(stacks[0], vec!['Z', 'N', 'D']);
+        assert_eq!(stacks[0], vec!["Z", "N", "D"]);
 
         Ok(())
     }
 
     #[test]
-    fn test_v9000() -> Result<(), Box<dyn Error>> {
+    fn test_v9000() -> Result<(), AocError> {
         let (stacks, move_commands) = crate::parser::parse_input(TEST_INPUT)?;
 
-        let final_stack = v9000(stacks, move_commands);
+        let final_stack = v9000(stacks, &move_commands)?;
 
-        assert_eq!(final_stack[0], vec!['Z', 'N', 'D', 'C']);
-        assert_eq!(final_stack[1], vec!['M']);
-        assert_eq!(final_stack[2], vec!['P']);
+        assert_eq!(final_stack[0], vec!["Z", "N", "D", "C"]);
+        assert_eq!(final_stack[1], vec!["M"]);
+        assert_eq!(final_stack[2], vec!["P"]);
 
         Ok(())
     }
 
     #[test]
-    fn test_step_v9001() -> Result<(), Box<dyn Error>> {
+    fn test_step_v9001() -> Result<(), AocError> {
         let (mut stacks, _) = crate::parser::parse_input(TEST_INPUT)?;
 
-        step_v9001(&mut stacks, &MoveCommand::new(2, 2, 3));
+        step(Crane::V9001, &mut stacks, &MoveCommand::new(2, 2, 3));
+
+        assert_eq!(stacks[2], vec!["P", "C", "D"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_v9001() -> Result<(), AocError> {
+        let (stacks, move_commands) = crate::parser::parse_input(TEST_INPUT)?;
+
+        let final_stack = v9001(stacks, &move_commands)?;
+
+        assert_eq!(final_stack[0], vec!["Z", "N", "D", "M"]);
+        assert_eq!(final_stack[1], vec!["C"]);
+        assert_eq!(final_stack[2], vec!["P"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_step_same_stack() -> Result<(), AocError> {
+        let (stacks, _) = crate::parser::parse_input(TEST_INPUT)?;
+
+        for crane in [Crane::V9000, Crane::V9001] {
+            let mut moved = stacks.clone();
+            step(crane, &mut moved, &MoveCommand::new(3, 2, 2));
+            assert_eq!(moved, stacks);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_moves() -> Result<(), AocError> {
+        let (stacks, _) = crate::parser::parse_input(TEST_INPUT)?;
+
+        assert_eq!(
+            v9000(stacks.clone(), &[MoveCommand::new(4, 1, 2)]),
+            Err(MoveError::NotEnoughCrates {
+                instruction: 1,
+                column: 1,
+                wanted: 4,
+                available: 2
+            })
+        );
+        assert_eq!(
+            v9001(stacks.clone(), &[MoveCommand::new(1, 0, 2)]),
+            Err(MoveError::NoSuchStack {
+                instruction: 1,
+                column: 0,
+                stacks: 3
+            })
+        );
 
-        assert_eq!(stacks[2], vec!['P', 'C', 'D']);
+        // the second move would only run out after the first one
+        let movements = [MoveCommand::new(3, 2, 1), MoveCommand::new(1, 2, 10)];
+        let error = replay(Crane::V9001, stacks.clone(), &movements)
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "Move 2 uses stack 10, but there are only 3 stacks"
+        );
+        let movements = [MoveCommand::new(3, 2, 1), MoveCommand::new(1, 2, 3)];
+        assert_eq!(
+            AocError::from(v9000(stacks, &movements).unwrap_err()).to_string(),
+            "Invalid puzzle state: Move 2 takes 1 crates from stack 2, which only holds 0"
+        );
 
         Ok(())
     }
 
     #[test]
-    fn test_v9001() -> Result<(), Box<dyn Error>> {
+    fn test_replay() -> Result<(), AocError> {
         let (stacks, move_commands) = crate::parser::parse_input(TEST_INPUT)?;
 
-        let final_stack = v9001(stacks, move_commands);
+        let states = replay(Crane::V9000, stacks.clone(), &move_commands)?.collect::<Vec<_>>();
+        assert_eq!(states.len(), 3);
+        assert_eq!(states[0].0, &move_commands[0]);
+        assert_eq!(states[0].1[0], vec!["Z", "N", "D"]);
+        assert_eq!(states[2].1, v9000(stacks.clone(), &move_commands)?);
 
-        assert_eq!(final_stack[0], vec!['Z', 'N', 'D', 'M']);
-        assert_eq!(final_stack[1], vec!['C']);
-        assert_eq!(final_stack[2], vec!['P']);
+        let last = replay(Crane::V9001, stacks.clone(), &move_commands)?.last();
+        assert_eq!(last.unwrap().1, v9001(stacks, &move_commands)?);
 
         Ok(())
     }
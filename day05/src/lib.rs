@@ -0,0 +1,56 @@
+pub mod crane;
+mod parser;
+pub mod render;
+pub mod types;
+
+use common::{AocResult, PartResult, Solution};
+use types::{MoveCommand, PrintableStack, Stacks};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (Stacks, Vec<MoveCommand>);
+    type Output1 = String;
+    type Output2 = String;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        parser::parse_input(input)
+    }
+
+    fn part_1(&self, (stacks, movements): &Self::Input) -> PartResult<String> {
+        Ok(crane::v9000(stacks.clone(), movements)?.top_elements())
+    }
+
+    fn part_2(&self, (stacks, movements): &Self::Input) -> PartResult<String> {
+        Ok(crane::v9001(stacks.clone(), movements)?.top_elements())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = r#"
+    [D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+    "#;
+
+    #[test]
+    fn test_part_1() {
+        let input = Day05.parse(TEST_INPUT).unwrap();
+        assert_eq!(Day05.part_1(&input).unwrap(), "CMZ")
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = Day05.parse(TEST_INPUT).unwrap();
+        assert_eq!(Day05.part_2(&input).unwrap(), "MCD")
+    }
+}
@@ -1,64 +1,126 @@
-use pest::{iterators::Pairs, Parser};
+use std::ops::Range;
+
+use common::{AocError, AocResult};
+use pest::{error::LineColLocation, iterators::Pairs, Parser};
 use pest_derive::Parser;
-use std::error::Error;
 
 #[derive(Parser)]
 #[grammar = "input.pest"] // relative to src
 struct InputParser;
 
-use crate::types::MoveCommand;
-use crate::types::Stacks;
+use crate::types::{Crate, MoveCommand, Stacks};
+
+// A crate and the columns of the line it's drawn on
+type PlacedCrate = (Range<usize>, Crate);
 
-pub fn parse_input(input: &str) -> Result<(Stacks, Vec<MoveCommand>), Box<dyn Error>> {
+pub fn parse_input(input: &str) -> AocResult<(Stacks, Vec<MoveCommand>)> {
     let mut move_commands = vec![];
-    let mut initial_status_rows = vec![];
+    let mut crates_rows = vec![];
+    let mut footer = None;
 
-    for line in input.lines() {
-        let rule = InputParser::parse(Rule::row, line);
-        if rule.is_err() {
+    for (index, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        // the line numbering the stacks says how many there are
+        if line
+            .chars()
+            .all(|c| c.is_ascii_digit() || c.is_whitespace())
+        {
+            footer = Some(parse_footer(line).map_err(|e| e.at_line(index + 1))?);
             continue;
         }
 
-        let rule = rule?.next().ok_or("Invalid line")?;
+        let rule = InputParser::parse(Rule::row, line)
+            .map_err(pest_error)
+            .and_then(|mut pairs| pairs.next().ok_or_else(|| AocError::parse("Invalid line")))
+            .map_err(|e| e.at_line(index + 1))?;
 
         match rule.as_rule() {
-            Rule::initialStatus => {
-                initial_status_rows.push(parse_initial_status(rule.into_inner()))
-            }
+            Rule::cratesRow => crates_rows.push((index + 1, parse_crates_row(rule.into_inner()))),
 
-            Rule::moveCommand => move_commands.push(parse_move_command(rule.into_inner())?),
+            Rule::moveCommand => move_commands
+                .push(parse_move_command(rule.into_inner()).map_err(|e| e.at_line(index + 1))?),
             _ => unreachable!(),
         }
     }
 
-    let mut stacks: Stacks = Default::default();
-    initial_status_rows.reverse();
-    for row in initial_status_rows {
-        row.into_iter()
-            .enumerate()
-            .filter(|(_, char)| char.is_some())
-            .for_each(|(index, opt_char)| stacks[index].push(opt_char.unwrap()))
+    let footer = footer.ok_or_else(|| AocError::parse("Missing the line numbering the stacks"))?;
+    let mut stacks: Stacks = vec![vec![]; footer.len()];
+    crates_rows.reverse();
+    for (line, row) in crates_rows {
+        for (columns, label) in row {
+            // the crate sits on the stack whose number is under it
+            let stack = footer
+                .iter()
+                .position(|number| number.start < columns.end && columns.start < number.end)
+                .ok_or_else(|| {
+                    AocError::parse(format!("Crate [{}] isn't above any stack", label))
+                        .at_line(line)
+                        .at_column(columns.start + 1)
+                })?;
+            stacks[stack].push(label);
+        }
     }
 
     Ok((stacks, move_commands))
 }
 
-fn parse_move_command(inner_rule: Pairs<Rule>) -> Result<MoveCommand, Box<dyn Error>> {
-    let mut inner_rule = inner_rule;
-    let amount = inner_rule.next().unwrap().as_str().parse()?;
-    let from_column = inner_rule.next().unwrap().as_str().parse()?;
-    let to_column = inner_rule.next().unwrap().as_str().parse()?;
+// The columns of each stack number, which have to go 1, 2, 3...
+fn parse_footer(line: &str) -> AocResult<Vec<Range<usize>>> {
+    let mut numbers = vec![];
+    let mut start = None;
+    for (column, c) in line.char_indices().chain([(line.len(), ' ')]) {
+        match (c.is_ascii_digit(), start) {
+            (true, None) => start = Some(column),
+            (false, Some(from)) => {
+                let number = line[from..column].parse::<usize>()?;
+                if number != numbers.len() + 1 {
+                    return Err(AocError::parse(format!(
+                        "Expected stack {}, found {}",
+                        numbers.len() + 1,
+                        number
+                    ))
+                    .at_column(from + 1));
+                }
+                numbers.push(from..column);
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    Ok(numbers)
+}
 
-    Ok(MoveCommand::new(amount, from_column, to_column))
+fn pest_error(error: pest::error::Error<Rule>) -> AocError {
+    let column = match error.line_col {
+        LineColLocation::Pos((_, column)) | LineColLocation::Span((_, column), _) => column,
+    };
+    AocError::parse("Expected a crates row or a move command").at_column(column)
 }
 
-fn parse_initial_status(inner_rule: Pairs<Rule>) -> Vec<Option<char>> {
+fn parse_move_command(inner_rule: Pairs<Rule>) -> AocResult<MoveCommand> {
+    let numbers = inner_rule
+        .map(|rule| rule.as_str().parse::<usize>())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    match numbers[..] {
+        [amount, from_column, to_column] => {
+            Ok(MoveCommand::new(amount as u64, from_column, to_column))
+        }
+        _ => Err(AocError::parse("Invalid move command")),
+    }
+}
+
+fn parse_crates_row(inner_rule: Pairs<Rule>) -> Vec<PlacedCrate> {
     inner_rule
-        .map(|r| match r.as_rule() {
-            Rule::boxLetter => Some(r.as_str().chars().next().unwrap()),
-            _ => None,
+        .filter(|r| r.as_rule() == Rule::crateBox)
+        .map(|r| {
+            let span = r.as_span();
+            let label = r.into_inner().as_str().to_string();
+            (span.start()..span.end(), label)
         })
-        .collect::<Vec<_>>()
+        .collect()
 }
 
 #[cfg(test)]
@@ -69,7 +131,7 @@ mod tests {
     [D]
 [N] [C]
 [Z] [M] [P]
-    1   2   3
+ 1   2   3
 
 move 1 from 2 to 1
 move 3 from 1 to 3
@@ -80,13 +142,60 @@ move 1 from 1 to 2
     #[test]
     fn test_parse() {
         let result = parse_input(TEST_INPUT);
-        match result {
-            Err(e) => panic!("{}", e.to_string()),
-            Ok(_) => {}
+        if let Err(e) = result {
+            panic!("{}", e)
         }
 
         let (stacks, move_commands) = result.unwrap();
-        assert_eq!(stacks[1], vec!['M', 'C', 'D']);
+        assert_eq!(stacks.len(), 3);
+        assert_eq!(stacks[1], vec!["M", "C", "D"]);
         assert_eq!(move_commands[0], MoveCommand::new(1, 2, 1))
     }
+
+    #[test]
+    fn test_parse_any_number_of_stacks() {
+        let (stacks, _) = parse_input("[A]\n 1   2").unwrap();
+        assert_eq!(stacks, vec![vec!["A"], vec![]]);
+
+        let input = "                                        [K]\n\
+                     [A] [B] [C] [D] [E] [F] [G] [H] [I] [J] [K]\n \
+                     1   2   3   4   5   6   7   8   9  10  11";
+        let (stacks, _) = parse_input(input).unwrap();
+        assert_eq!(stacks.len(), 11);
+        assert_eq!(stacks[10], vec!["K", "K"]);
+    }
+
+    #[test]
+    fn test_parse_longer_labels() {
+        let input = "     [bb]\n[AA] [CD] [e]\n 1    2    3";
+        let (stacks, _) = parse_input(input).unwrap();
+        assert_eq!(stacks, vec![vec!["AA"], vec!["CD", "bb"], vec!["e"]]);
+    }
+
+    #[test]
+    fn test_parse_invalid_line() {
+        let result = parse_input("[A] [b!]\n 1   2\n\nmove 1 from 1 to 2");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Parse error at 1:5: Expected a crates row or a move command"
+        );
+        assert!(parse_input("[A] [B]\n 1   2\n\nmove 1 form 1 to 2").is_err());
+    }
+
+    #[test]
+    fn test_parse_invalid_drawing() {
+        let error = |input: &str| parse_input(input).unwrap_err().to_string();
+        assert_eq!(
+            error("[A] [B]\n\nmove 1 from 1 to 2"),
+            "Parse error: Missing the line numbering the stacks"
+        );
+        assert_eq!(
+            error("[A] [B]\n 1   3"),
+            "Parse error at 2:6: Expected stack 2, found 3"
+        );
+        assert_eq!(
+            error("[A]     [B]\n 1   2"),
+            "Parse error at 1:9: Crate [B] isn't above any stack"
+        );
+    }
 }
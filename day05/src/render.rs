@@ -0,0 +1,88 @@
+use crate::types::Stacks;
+
+/// The stacks drawn the way the puzzle does, with the stack numbers under
+/// them. Drawings read back by the parser give the same stacks.
+pub fn ascii(stacks: &Stacks) -> String {
+    // every column is as wide as the widest crate
+    let width = stacks
+        .iter()
+        .flatten()
+        .map(|label| label.len() + 2)
+        .max()
+        .unwrap_or(3);
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+
+    let mut lines = (0..height)
+        .rev()
+        .map(|level| {
+            let row = stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(label) => format!("{:^width$}", format!("[{}]", label)),
+                    None => " ".repeat(width),
+                })
+                .collect::<Vec<_>>();
+            row.join(" ")
+        })
+        .collect::<Vec<_>>();
+
+    let numbers = (1..=stacks.len())
+        .map(|number| format!("{:^width$}", number))
+        .collect::<Vec<_>>();
+    lines.push(numbers.join(" "));
+
+    lines
+        .iter()
+        .map(|line| format!("{}\n", line.trim_end()))
+        .collect()
+}
+
+/// Two drawings next to each other, lined up at the bottom so that the stack
+/// numbers end up on the same line
+pub fn side_by_side(left: &str, right: &str) -> String {
+    let width = left.lines().map(str::len).max().unwrap_or(0);
+    let height = left.lines().count().max(right.lines().count());
+
+    pad_top(left, height)
+        .zip(pad_top(right, height))
+        .map(|(l, r)| format!("{}\n", format!("{:width$}   {}", l, r).trim_end()))
+        .collect()
+}
+
+fn pad_top(drawing: &str, height: usize) -> impl Iterator<Item = &str> {
+    std::iter::repeat_n("", height - drawing.lines().count()).chain(drawing.lines())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_input;
+
+    const DRAWING: &str = "    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3\n";
+
+    #[test]
+    fn test_ascii() {
+        let (stacks, _) = parse_input(DRAWING).unwrap();
+        assert_eq!(ascii(&stacks), DRAWING);
+        assert_eq!(ascii(&vec![vec![], vec![]]), " 1   2\n");
+    }
+
+    #[test]
+    fn test_ascii_round_trip() {
+        let stacks: Stacks = vec![vec!["AA".into()], vec![], vec!["b".into(), "CDE".into()]];
+        let drawing = ascii(&stacks);
+        assert_eq!(
+            drawing,
+            "            [CDE]\n[AA]         [b]\n  1     2     3\n"
+        );
+        assert_eq!(parse_input(&drawing).unwrap().0, stacks);
+    }
+
+    #[test]
+    fn test_side_by_side() {
+        assert_eq!(
+            side_by_side("[A]\n 1\n", "    [C]\n[B] [D]\n 1   2\n"),
+            "          [C]\n[A]   [B] [D]\n 1     1   2\n"
+        );
+    }
+}
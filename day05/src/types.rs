@@ -1,4 +1,8 @@
-pub type Stacks = [Vec<char>; 9];
+// A crate's label, usually a single letter
+pub type Crate = String;
+
+// The stacks from left to right, each from the bottom up
+pub type Stacks = Vec<Vec<Crate>>;
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct MoveCommand {
@@ -16,12 +20,47 @@ impl MoveCommand {
     }
 }
 
+impl std::fmt::Display for MoveCommand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.amount, self.from_column, self.to_column
+        )
+    }
+}
+
 pub trait PrintableStack {
     fn top_elements(&self) -> String;
 }
 
 impl PrintableStack for Stacks {
+    // Empty stacks have no top, so they are left out
     fn top_elements(&self) -> String {
-        self.iter().map(|s| s.last().unwrap_or(&' ')).collect()
+        self.iter().filter_map(|s| s.last()).cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_top_elements() {
+        let stacks: Stacks = vec![
+            vec!["Z".into(), "N".into()],
+            vec![],
+            vec!["p".into(), "QX".into()],
+        ];
+        assert_eq!(stacks.top_elements(), "NQX");
+        assert_eq!(Stacks::new().top_elements(), "");
+    }
+
+    #[test]
+    fn test_display_move() {
+        assert_eq!(
+            MoveCommand::new(3, 1, 10).to_string(),
+            "move 3 from 1 to 10"
+        );
     }
 }
@@ -0,0 +1,200 @@
+use std::collections::{HashMap, HashSet};
+
+use common::{Alternative, AocError, AocResult, Part, PartResult, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = String;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part_1(&self, datastream: &Self::Input) -> PartResult<usize> {
+        find_marker(datastream, 4).ok_or_else(|| AocError::no_solution("Marker not found"))
+    }
+
+    fn part_2(&self, datastream: &Self::Input) -> PartResult<usize> {
+        find_marker(datastream, 14).ok_or_else(|| AocError::no_solution("Marker not found"))
+    }
+
+    fn alternatives(&self) -> Vec<Alternative<Self>> {
+        vec![
+            Alternative::new("unsafe_find_marker", Part::One, |_, datastream: &String| {
+                unsafe_marker(datastream, 4)
+            }),
+            Alternative::new("unsafe_find_marker", Part::Two, |_, datastream: &String| {
+                unsafe_marker(datastream, 14)
+            }),
+        ]
+    }
+}
+
+fn find_marker(input: &str, length: usize) -> Option<usize> {
+    input
+        .as_bytes()
+        .windows(length)
+        .enumerate()
+        .find_map(|(i, window)| all_different(window).then_some(i + length))
+}
+
+fn all_different(chars: &[u8]) -> bool {
+    chars.iter().cloned().collect::<HashSet<u8>>().len() == chars.len()
+}
+
+fn unsafe_marker(datastream: &str, length: usize) -> PartResult<String> {
+    unsafe_find_marker(datastream, length)
+        .map(|marker| marker.to_string())
+        .ok_or_else(|| AocError::no_solution("Marker not found"))
+}
+
+// so... this is just for fun, I'm not even sure it's actually more efficient
+fn unsafe_find_marker(input: &str, length: usize) -> Option<usize> {
+    if input.len() < length {
+        return None;
+    }
+
+    let mut charmap: HashMap<char, u64> = HashMap::new();
+    let mut head: *const u8 = input.as_ptr();
+    let mut tail: *const u8 = input.as_ptr();
+    for _ in 0..length {
+        let current_char = unsafe { *tail };
+        charmap
+            .entry(current_char as char)
+            .and_modify(|e| *e += 1)
+            .or_insert(1);
+        tail = unsafe { tail.add(1) };
+    }
+
+    tail = unsafe { tail.sub(1) };
+
+    for i in 0..(input.len() - length) {
+        if charmap.keys().len() == length {
+            return Some(i + length);
+        }
+        let (head_char, tail_char) = unsafe {
+            let head_char = *head as char;
+            head = head.add(1);
+            tail = tail.add(1);
+            let tail_char = *tail as char;
+            (head_char, tail_char)
+        };
+
+        let count = charmap.get(&head_char).unwrap();
+        if *count == 1 {
+            charmap.remove(&head_char);
+        } else {
+            charmap.insert(head_char, count - 1);
+        }
+
+        charmap
+            .entry(tail_char)
+            .and_modify(|e| *e += 1)
+            .or_insert(1);
+    }
+
+    // the loop stops before moving past the last window, so check it here
+    (charmap.keys().len() == length).then_some(input.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_marker_1() {
+        assert_eq!(find_marker("bvwbjplbgvbhsrlpgdmjqwftvncz", 4), Some(5));
+    }
+
+    #[test]
+    fn test_marker_2() {
+        assert_eq!(find_marker("nppdvjthqldpwncqszvftbrmjlhg", 4), Some(6));
+    }
+
+    #[test]
+    fn test_marker_3() {
+        assert_eq!(
+            find_marker("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 4),
+            Some(10)
+        );
+    }
+
+    #[test]
+    fn test_marker_4() {
+        assert_eq!(find_marker("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 4), Some(11));
+    }
+
+    #[test]
+    fn test_message_1() {
+        assert_eq!(find_marker("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 14), Some(19));
+    }
+
+    #[test]
+    fn test_message_2() {
+        assert_eq!(find_marker("bvwbjplbgvbhsrlpgdmjqwftvncz", 14), Some(23));
+    }
+
+    #[test]
+    fn test_message_3() {
+        assert_eq!(find_marker("nppdvjthqldpwncqszvftbrmjlhg", 14), Some(23));
+    }
+
+    #[test]
+    fn test_message_4() {
+        assert_eq!(
+            find_marker("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 14),
+            Some(29)
+        );
+    }
+
+    #[test]
+    fn test_message_5() {
+        assert_eq!(
+            find_marker("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 14),
+            Some(26)
+        );
+    }
+
+    #[test]
+    fn test_unsafe_marker_1() {
+        assert_eq!(
+            unsafe_find_marker("bvwbjplbgvbhsrlpgdmjqwftvncz", 4),
+            Some(5)
+        );
+    }
+
+    #[test]
+    fn test_unsafe_marker_2() {
+        assert_eq!(
+            unsafe_find_marker("nppdvjthqldpwncqszvftbrmjlhg", 4),
+            Some(6)
+        );
+    }
+
+    #[test]
+    fn test_unsafe_marker_3() {
+        assert_eq!(
+            unsafe_find_marker("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 4),
+            Some(10)
+        );
+    }
+
+    #[test]
+    fn test_unsafe_marker_4() {
+        assert_eq!(
+            unsafe_find_marker("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 4),
+            Some(11)
+        );
+    }
+
+    #[test]
+    fn test_unsafe_marker_at_the_end() {
+        assert_eq!(unsafe_find_marker("aabcd", 4), Some(5));
+        assert_eq!(unsafe_find_marker("abcd", 4), Some(4));
+        assert_eq!(find_marker("aabcd", 4), Some(5));
+        assert_eq!(unsafe_find_marker("aabc", 4), None);
+    }
+}
@@ -0,0 +1,327 @@
+use std::collections::BTreeMap;
+
+use common::{AocError, AocResult};
+
+pub type NodeId = usize;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NodeKind {
+    // children by name
+    Folder(BTreeMap<String, NodeId>),
+    File(usize),
+}
+
+#[derive(Debug, Clone)]
+pub struct Node {
+    pub name: String,
+    pub parent: Option<NodeId>,
+    pub kind: NodeKind,
+}
+
+impl Node {
+    pub fn is_folder(&self) -> bool {
+        matches!(self.kind, NodeKind::Folder(_))
+    }
+}
+
+// Every folder and file in one arena, where nodes refer to each other by
+// index. Nodes are only ever added, so children always come after their
+// parent.
+#[derive(Debug, Clone)]
+pub struct FileSystem {
+    nodes: Vec<Node>,
+}
+
+impl Default for FileSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FileSystem {
+    pub const ROOT: NodeId = 0;
+
+    pub fn new() -> Self {
+        Self {
+            nodes: vec![Node {
+                name: "/".to_string(),
+                parent: None,
+                kind: NodeKind::Folder(BTreeMap::new()),
+            }],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        // there's always the root
+        false
+    }
+
+    pub fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id]
+    }
+
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.nodes[id].parent
+    }
+
+    pub fn child(&self, id: NodeId, name: &str) -> Option<NodeId> {
+        match &self.nodes[id].kind {
+            NodeKind::Folder(children) => children.get(name).copied(),
+            NodeKind::File(_) => None,
+        }
+    }
+
+    // The children of a folder sorted by name, and nothing for a file
+    pub fn children(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        let children = match &self.nodes[id].kind {
+            NodeKind::Folder(children) => Some(children.values().copied()),
+            NodeKind::File(_) => None,
+        };
+        children.into_iter().flatten()
+    }
+
+    pub fn folders(&self) -> impl Iterator<Item = NodeId> + '_ {
+        (0..self.nodes.len()).filter(|id| self.nodes[*id].is_folder())
+    }
+
+    // The folder called `name` in `parent`, created if it's not there yet
+    pub fn mkdir(&mut self, parent: NodeId, name: &str) -> AocResult<NodeId> {
+        match self.child(parent, name) {
+            Some(id) if self.nodes[id].is_folder() => Ok(id),
+            Some(_) => Err(AocError::invalid_state(format!(
+                "{} is a file, not a folder",
+                self.path(parent).join(name)
+            ))),
+            None => self.add(parent, name, NodeKind::Folder(BTreeMap::new())),
+        }
+    }
+
+    // Adds a file to `parent`, or sets the size of the one already there
+    pub fn touch(&mut self, parent: NodeId, name: &str, size: usize) -> AocResult<NodeId> {
+        match self.child(parent, name) {
+            Some(id) if !self.nodes[id].is_folder() => {
+                self.nodes[id].kind = NodeKind::File(size);
+                Ok(id)
+            }
+            Some(_) => Err(AocError::invalid_state(format!(
+                "{} is a folder, not a file",
+                self.path(parent).join(name)
+            ))),
+            None => self.add(parent, name, NodeKind::File(size)),
+        }
+    }
+
+    fn add(&mut self, parent: NodeId, name: &str, kind: NodeKind) -> AocResult<NodeId> {
+        let id = self.nodes.len();
+        match &mut self.nodes[parent].kind {
+            NodeKind::Folder(children) => children.insert(name.to_string(), id),
+            NodeKind::File(_) => {
+                return Err(AocError::invalid_state(format!(
+                    "Can't add {} to the file {}",
+                    name,
+                    self.path(parent)
+                )))
+            }
+        };
+        self.nodes.push(Node {
+            name: name.to_string(),
+            parent: Some(parent),
+            kind,
+        });
+        Ok(id)
+    }
+
+    // The node at an absolute path like /a/e
+    pub fn find(&self, path: &str) -> Option<NodeId> {
+        self.resolve(Self::ROOT, path).ok()
+    }
+
+    // The node `path` leads to from the folder `from`, where the path can be
+    // absolute and go up with ..
+    pub fn resolve(&self, from: NodeId, path: &str) -> AocResult<NodeId> {
+        let (start, names) = split_path(from, path);
+        names.into_iter().try_fold(start, |id, name| match name {
+            ".." => self.up(id),
+            _ => self.child(id, name).ok_or_else(|| {
+                AocError::invalid_state(format!(
+                    "No such file or folder {}",
+                    self.path(id).join(name)
+                ))
+            }),
+        })
+    }
+
+    // Same as `resolve`, creating the folders that aren't there yet
+    pub fn mkdir_all(&mut self, from: NodeId, path: &str) -> AocResult<NodeId> {
+        let (start, names) = split_path(from, path);
+        names.into_iter().try_fold(start, |id, name| match name {
+            ".." => self.up(id),
+            _ => self.mkdir(id, name),
+        })
+    }
+
+    fn up(&self, id: NodeId) -> AocResult<NodeId> {
+        self.parent(id)
+            .ok_or_else(|| AocError::invalid_state("Can't go up from root"))
+    }
+
+    pub fn path(&self, id: NodeId) -> Path {
+        let mut names = vec![];
+        let mut current = id;
+        while let Some(parent) = self.nodes[current].parent {
+            names.push(self.nodes[current].name.clone());
+            current = parent;
+        }
+        names.reverse();
+        Path(names)
+    }
+
+    // The total size of every node, indexed by id. Going through the nodes
+    // backwards sees every child before its parent.
+    pub fn sizes(&self) -> Vec<usize> {
+        let mut sizes = vec![0; self.nodes.len()];
+        for (id, node) in self.nodes.iter().enumerate().rev() {
+            if let NodeKind::File(size) = node.kind {
+                sizes[id] = size;
+            }
+            if let Some(parent) = node.parent {
+                sizes[parent] += sizes[id];
+            }
+        }
+        sizes
+    }
+}
+
+// Where `path` starts from, and the names to follow from there
+fn split_path(from: NodeId, path: &str) -> (NodeId, Vec<&str>) {
+    let start = match path.starts_with('/') {
+        true => FileSystem::ROOT,
+        false => from,
+    };
+    let names = path
+        .split('/')
+        .filter(|name| !name.is_empty() && *name != ".")
+        .collect();
+    (start, names)
+}
+
+// The names of the folders from the root down to a node
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path(Vec<String>);
+
+impl Path {
+    fn join(&self, name: &str) -> String {
+        let mut names = self.0.clone();
+        names.push(name.to_string());
+        Path(names).to_string()
+    }
+}
+
+impl std::fmt::Display for Path {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "/{}", self.0.join("/"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filesystem() -> FileSystem {
+        let mut fs = FileSystem::new();
+        let a = fs.mkdir(FileSystem::ROOT, "a").unwrap();
+        let ab = fs.mkdir(FileSystem::ROOT, "ab").unwrap();
+        let e = fs.mkdir(a, "e").unwrap();
+        fs.touch(FileSystem::ROOT, "b.txt", 100).unwrap();
+        fs.touch(a, "f", 20).unwrap();
+        fs.touch(e, "i", 3).unwrap();
+        fs.touch(ab, "g", 4000).unwrap();
+        fs
+    }
+
+    #[test]
+    fn test_find() {
+        let fs = filesystem();
+        assert_eq!(fs.find("/"), Some(FileSystem::ROOT));
+        let e = fs.find("/a/e").unwrap();
+        assert_eq!(fs.node(e).name, "e");
+        assert_eq!(fs.path(e).to_string(), "/a/e");
+        assert_eq!(fs.path(FileSystem::ROOT).to_string(), "/");
+        assert_eq!(fs.find("/a/x"), None);
+        assert_eq!(fs.find("/b.txt/x"), None);
+    }
+
+    #[test]
+    fn test_resolve() {
+        let fs = filesystem();
+        let a = fs.find("/a").unwrap();
+        assert_eq!(
+            fs.resolve(a, "e/../../ab").unwrap(),
+            fs.find("/ab").unwrap()
+        );
+        assert_eq!(fs.resolve(a, "./e/i").unwrap(), fs.find("/a/e/i").unwrap());
+        assert_eq!(fs.resolve(a, "/").unwrap(), FileSystem::ROOT);
+        assert_eq!(
+            fs.resolve(a, "x").unwrap_err().to_string(),
+            "Invalid puzzle state: No such file or folder /a/x"
+        );
+        assert!(fs.resolve(a, "../..").is_err());
+    }
+
+    #[test]
+    fn test_mkdir_all() {
+        let mut fs = filesystem();
+        let nodes = fs.len();
+        let a = fs.find("/a").unwrap();
+
+        let y = fs.mkdir_all(a, "e/../x/y").unwrap();
+        assert_eq!(fs.path(y).to_string(), "/a/x/y");
+        assert_eq!(fs.len(), nodes + 2);
+        assert_eq!(fs.mkdir_all(FileSystem::ROOT, "/a/x/y").unwrap(), y);
+        assert!(fs.mkdir_all(a, "f/z").is_err());
+    }
+
+    #[test]
+    fn test_sizes() {
+        let fs = filesystem();
+        let sizes = fs.sizes();
+        let size = |path| sizes[fs.find(path).unwrap()];
+
+        assert_eq!(size("/"), 4123);
+        // /ab starts like /a, but isn't inside it
+        assert_eq!(size("/a"), 23);
+        assert_eq!(size("/ab"), 4000);
+        assert_eq!(size("/a/e/i"), 3);
+        assert_eq!(fs.folders().count(), 4);
+    }
+
+    #[test]
+    fn test_mkdir_and_touch_again() {
+        let mut fs = filesystem();
+        let a = fs.find("/a").unwrap();
+        let nodes = fs.len();
+
+        assert_eq!(fs.mkdir(FileSystem::ROOT, "a").unwrap(), a);
+        fs.touch(a, "f", 25).unwrap();
+        assert_eq!(fs.len(), nodes);
+        assert_eq!(fs.sizes()[a], 28);
+        assert_eq!(
+            fs.children(a)
+                .map(|id| fs.node(id).name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["e", "f"]
+        );
+
+        assert_eq!(
+            fs.mkdir(a, "f").unwrap_err().to_string(),
+            "Invalid puzzle state: /a/f is a file, not a folder"
+        );
+        assert!(fs.touch(FileSystem::ROOT, "a", 1).is_err());
+        let f = fs.find("/a/f").unwrap();
+        assert!(fs.touch(f, "x", 1).is_err());
+    }
+}
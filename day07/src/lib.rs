@@ -0,0 +1,386 @@
+use common::{AocError, AocResult, Graph, PartResult, Solution};
+
+pub mod filesystem;
+pub mod query;
+mod shell;
+
+use filesystem::{FileSystem, NodeId};
+use shell::Line;
+
+pub struct Day07 {
+    pub disk_size: usize,
+    // the free space the update needs
+    pub update_size: usize,
+}
+
+impl Default for Day07 {
+    fn default() -> Self {
+        Self {
+            disk_size: 70000000,
+            update_size: 30000000,
+        }
+    }
+}
+
+impl Solution for Day07 {
+    type Input = Terminal;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, terminal: &Self::Input) -> PartResult<usize> {
+        Ok(part_1(terminal))
+    }
+
+    fn part_2(&self, terminal: &Self::Input) -> PartResult<usize> {
+        part_2(terminal, self.disk_size, self.update_size)
+    }
+}
+
+#[derive(Debug)]
+pub struct Terminal {
+    fs: FileSystem,
+    current_folder: NodeId,
+    // the folder the output of the last ls belongs to
+    listing: Option<NodeId>,
+}
+
+impl Terminal {
+    fn new() -> Terminal {
+        Terminal {
+            fs: FileSystem::new(),
+            current_folder: FileSystem::ROOT,
+            listing: None,
+        }
+    }
+
+    pub fn filesystem(&self) -> &FileSystem {
+        &self.fs
+    }
+
+    pub fn pwd(&self) -> String {
+        self.fs.path(self.current_folder).to_string()
+    }
+
+    fn cd(&mut self, path: &str) -> AocResult<()> {
+        self.current_folder = self.fs.mkdir_all(self.current_folder, path)?;
+        self.listing = None;
+        Ok(())
+    }
+
+    fn ls(&mut self, path: Option<&str>) -> AocResult<()> {
+        let folder = self
+            .fs
+            .mkdir_all(self.current_folder, path.unwrap_or("."))?;
+        self.listing = Some(folder);
+        Ok(())
+    }
+
+    fn listing(&self) -> AocResult<NodeId> {
+        self.listing
+            .ok_or_else(|| AocError::parse("Output without an ls before it"))
+    }
+
+    // Listing a folder again only updates what's already known about it
+    fn run(&mut self, line: Line) -> AocResult<()> {
+        match line {
+            Line::Cd(path) => self.cd(&path),
+            Line::Ls(path) => self.ls(path.as_deref()),
+            Line::Dir(name) => {
+                self.fs.mkdir(self.listing()?, &name)?;
+                Ok(())
+            }
+            Line::File(name, size) => {
+                self.fs.touch(self.listing()?, &name, size)?;
+                Ok(())
+            }
+        }
+    }
+
+    // The total size of every folder
+    fn folder_sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        let sizes = self.fs.sizes();
+        self.fs.folders().map(move |id| sizes[id])
+    }
+
+    /// The directory tree as a GraphViz graph, with the total size of every
+    /// folder and the size of every file
+    pub fn to_dot(&self) -> String {
+        let sizes = self.fs.sizes();
+
+        // nodes go in by id, so graph indices and node ids are the same
+        let mut graph = Graph::directed();
+        for id in 0..self.fs.len() {
+            graph.add_node(id);
+            if let Some(parent) = self.fs.parent(id) {
+                graph.add_edge(parent, id);
+            }
+        }
+
+        graph.to_dot("filesystem", |id| {
+            let node = self.fs.node(*id);
+            let shape = match node.is_folder() {
+                true => "folder",
+                false => "note",
+            };
+            vec![
+                ("label", format!("{} ({})", node.name, sizes[*id])),
+                ("shape", shape.to_string()),
+            ]
+        })
+    }
+}
+
+fn parse_input(input: &str) -> AocResult<Terminal> {
+    let mut terminal = Terminal::new();
+
+    for (index, line) in input.lines().enumerate() {
+        line.parse()
+            .and_then(|line| terminal.run(line))
+            .map_err(|e| in_transcript(e).at_line(index + 1))?;
+    }
+
+    Ok(terminal)
+}
+
+// A transcript that contradicts itself is as broken as one that can't be read
+fn in_transcript(error: AocError) -> AocError {
+    match error {
+        AocError::InvalidState(message) => AocError::parse(message),
+        other => other,
+    }
+}
+
+fn part_1(terminal: &Terminal) -> usize {
+    terminal.folder_sizes().filter(|size| *size <= 100000).sum()
+}
+
+fn part_2(terminal: &Terminal, disk_size: usize, update_size: usize) -> AocResult<usize> {
+    query::deletion_candidates(terminal.filesystem(), disk_size, update_size)?
+        .first()
+        .map(|(_, size)| *size)
+        .ok_or_else(|| AocError::no_solution("No folder frees enough space"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use filesystem::NodeKind;
+
+    const TEST_INPUT: &str = r#"$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k
+"#;
+
+    #[test]
+    fn terminal_can_go_to_root() {
+        let mut terminal = Terminal::new();
+        terminal.cd("something").unwrap();
+        terminal.cd("/").unwrap();
+
+        assert_eq!(terminal.pwd(), "/")
+    }
+
+    #[test]
+    fn terminal_can_change_directory() {
+        let mut terminal = Terminal::new();
+        terminal.cd("something").unwrap();
+
+        assert_eq!(terminal.pwd(), "/something")
+    }
+
+    #[test]
+    fn terminal_can_change_directory_multiple_times() {
+        let mut terminal = Terminal::new();
+        terminal.cd("some").unwrap();
+        terminal.cd("inner").unwrap();
+        terminal.cd("path").unwrap();
+
+        assert_eq!(terminal.pwd(), "/some/inner/path");
+    }
+
+    #[test]
+    fn terminal_can_go_one_folder_up() {
+        let mut terminal = Terminal::new();
+        for folder in ["some", "inner", "path"] {
+            terminal.cd(folder).unwrap();
+        }
+
+        terminal.cd("..").unwrap();
+        assert_eq!(terminal.pwd(), "/some/inner");
+    }
+
+    #[test]
+    fn test_parse_input_changes_folders() {
+        let terminal = parse_input(TEST_INPUT).unwrap();
+        assert_eq!(terminal.pwd(), "/d");
+    }
+
+    #[test]
+    fn test_parse_input_saves_files() {
+        let terminal = parse_input(TEST_INPUT).unwrap();
+        let fs = terminal.filesystem();
+        let folder = fs.find("/a/e").expect("folder not found");
+        let files = fs.children(folder).collect::<Vec<_>>();
+        assert_eq!(files.len(), 1);
+        let file = fs.node(files[0]);
+        assert_eq!(file.name, "i");
+        assert_eq!(file.kind, NodeKind::File(584));
+    }
+
+    #[test]
+    fn test_calculate_folder_sizes() {
+        let terminal = parse_input(TEST_INPUT).unwrap();
+        let fs = terminal.filesystem();
+
+        let sizes = fs.sizes();
+        assert_eq!(sizes[fs.find("/a/e").unwrap()], 584);
+        assert_eq!(sizes[fs.find("/a").unwrap()], 94853);
+    }
+
+    #[test]
+    fn test_folders_sharing_a_prefix() {
+        let terminal = parse_input(
+            "$ cd /\n$ ls\ndir a\ndir ab\n$ cd a\n$ ls\n10 x\n$ cd ..\n$ cd ab\n$ ls\n20 y",
+        )
+        .unwrap();
+        let mut sizes = terminal.folder_sizes().collect::<Vec<_>>();
+        sizes.sort();
+        assert_eq!(sizes, vec![10, 20, 30]);
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let dot = parse_input(TEST_INPUT).unwrap().to_dot();
+
+        assert!(dot.starts_with(
+            "digraph \"filesystem\" {\n    n0 [label=\"/ (48381165)\", shape=\"folder\"];\n"
+        ));
+        assert!(dot.contains("[label=\"a (94853)\", shape=\"folder\"];\n"));
+        assert!(dot.contains("[label=\"i (584)\", shape=\"note\"];\n"));
+        // 3 folders and 10 files below the root
+        assert_eq!(dot.matches(" -> ").count(), 13);
+    }
+
+    #[test]
+    fn test_cd_with_paths() {
+        let mut terminal = Terminal::new();
+        terminal.cd("a/b/../c").unwrap();
+        assert_eq!(terminal.pwd(), "/a/c");
+        terminal.cd("/a/b").unwrap();
+        assert_eq!(terminal.pwd(), "/a/b");
+        terminal.cd("../../d").unwrap();
+        assert_eq!(terminal.pwd(), "/d");
+        assert!(terminal.cd("../..").is_err());
+    }
+
+    #[test]
+    fn test_ls_with_a_path() {
+        let terminal = parse_input("$ cd /a\n$ ls ../b\n5 x\n$ ls\n7 y").unwrap();
+        let fs = terminal.filesystem();
+        let sizes = fs.sizes();
+        assert_eq!(sizes[fs.find("/b").unwrap()], 5);
+        assert_eq!(sizes[fs.find("/a").unwrap()], 7);
+    }
+
+    #[test]
+    fn test_listing_twice() {
+        let twice = format!("{}$ cd /\n$ ls\ndir a\n14848514 b.txt\n", TEST_INPUT);
+        assert_eq!(part_1(&parse_input(&twice).unwrap()), 95437);
+        assert_eq!(
+            Day07::default()
+                .part_2(&parse_input(&twice).unwrap())
+                .unwrap(),
+            24933642
+        );
+    }
+
+    #[test]
+    fn test_transcript_errors() {
+        let error = |input: &str| parse_input(input).unwrap_err().to_string();
+        assert_eq!(
+            error("$ cd /\n$ mv a b"),
+            "Parse error at line 2: Unknown command 'mv'"
+        );
+        assert_eq!(
+            error("$ cd /\n10 a.txt"),
+            "Parse error at line 2: Output without an ls before it"
+        );
+        assert_eq!(
+            error("$ ls\n10 a.txt\n$ cd a.txt"),
+            "Parse error at line 3: /a.txt is a file, not a folder"
+        );
+        assert_eq!(
+            error("$ cd /\n$ cd .."),
+            "Parse error at line 2: Can't go up from root"
+        );
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(&parse_input(TEST_INPUT).unwrap()), 95437);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day07::default()
+                .part_2(&parse_input(TEST_INPUT).unwrap())
+                .unwrap(),
+            24933642
+        );
+    }
+
+    #[test]
+    fn test_part_2_on_a_smaller_disk() {
+        let day = Day07 {
+            disk_size: 50000000,
+            update_size: 10000000,
+        };
+        // 48381165 used, so 8381165 more must go: /d is the smallest that's enough
+        assert_eq!(
+            day.part_2(&parse_input(TEST_INPUT).unwrap()).unwrap(),
+            24933642
+        );
+
+        // even deleting everything can't make room for it
+        let day = Day07 {
+            disk_size: 50000000,
+            update_size: 60000000,
+        };
+        assert!(day.part_2(&parse_input(TEST_INPUT).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_parse_invalid_input() {
+        assert_eq!(
+            parse_input("$ cd /\n$ ls\n12k a.txt")
+                .unwrap_err()
+                .to_string(),
+            "Parse error at line 3: invalid digit found in string"
+        );
+    }
+}
@@ -0,0 +1,211 @@
+use std::fmt::Write;
+use std::str::FromStr;
+
+use common::{AocError, AocResult};
+
+use crate::filesystem::{FileSystem, NodeId, NodeKind};
+
+// Questions about a reconstructed disk, written like the shell commands
+// they imitate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Query {
+    // `tree`: everything, indented by depth
+    Tree,
+    // `du` or `du -d N`: the size of every folder at most N levels down
+    Du(Option<usize>),
+    // `find -size +N`: every file bigger than N
+    Find(usize),
+    // `free N`: the folders that would each free N bytes if deleted
+    Free(usize),
+}
+
+impl FromStr for Query {
+    type Err = AocError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let words = input.split_whitespace().collect::<Vec<_>>();
+        match words[..] {
+            ["tree"] => Ok(Query::Tree),
+            ["du"] => Ok(Query::Du(None)),
+            ["du", "-d", depth] => Ok(Query::Du(Some(depth.parse()?))),
+            ["find", "-size", size] => {
+                let size = size
+                    .strip_prefix('+')
+                    .ok_or_else(|| AocError::parse("find only supports -size +N"))?;
+                Ok(Query::Find(size.parse()?))
+            }
+            ["free", bytes] => Ok(Query::Free(bytes.parse()?)),
+            _ => Err(AocError::parse(format!(
+                "Unknown query '{}', try tree, du [-d N], find -size +N or free N",
+                input.trim()
+            ))),
+        }
+    }
+}
+
+impl Query {
+    pub fn run(&self, fs: &FileSystem) -> AocResult<String> {
+        let sizes = fs.sizes();
+        // writing to a String can't fail
+        let mut output = String::new();
+
+        match self {
+            Query::Tree => {
+                for (id, depth) in walk(fs) {
+                    let node = fs.node(id);
+                    let kind = match node.kind {
+                        NodeKind::Folder(_) => "dir",
+                        NodeKind::File(_) => "file",
+                    };
+                    let indent = "  ".repeat(depth);
+                    writeln!(
+                        output,
+                        "{}- {} ({}, size={})",
+                        indent, node.name, kind, sizes[id]
+                    )
+                    .unwrap();
+                }
+            }
+            Query::Du(max_depth) => {
+                // like du, a folder comes after everything inside it
+                let mut folders = walk(fs)
+                    .filter(|(id, depth)| {
+                        fs.node(*id).is_folder() && max_depth.is_none_or(|max| *depth <= max)
+                    })
+                    .collect::<Vec<_>>();
+                folders.reverse();
+                for (id, _) in folders {
+                    writeln!(output, "{}\t{}", sizes[id], fs.path(id)).unwrap();
+                }
+            }
+            Query::Find(min_size) => {
+                for (id, _) in walk(fs) {
+                    if !fs.node(id).is_folder() && sizes[id] > *min_size {
+                        writeln!(output, "{}", fs.path(id)).unwrap();
+                    }
+                }
+            }
+            Query::Free(bytes) => {
+                for (id, size) in folders_freeing(fs, *bytes) {
+                    writeln!(output, "{}\t{}", size, fs.path(id)).unwrap();
+                }
+            }
+        }
+
+        Ok(output)
+    }
+}
+
+// Every node with its depth, parents first and children sorted by name
+fn walk(fs: &FileSystem) -> impl Iterator<Item = (NodeId, usize)> + '_ {
+    let mut stack = vec![(FileSystem::ROOT, 0)];
+    std::iter::from_fn(move || {
+        let (id, depth) = stack.pop()?;
+        let children = fs.children(id).collect::<Vec<_>>();
+        stack.extend(children.into_iter().rev().map(|child| (child, depth + 1)));
+        Some((id, depth))
+    })
+}
+
+/// The folders that would each leave at least `wanted` bytes free on a disk
+/// of `disk_size` if deleted, smallest first, with their sizes
+pub fn deletion_candidates(
+    fs: &FileSystem,
+    disk_size: usize,
+    wanted: usize,
+) -> AocResult<Vec<(NodeId, usize)>> {
+    let free_space = disk_size
+        .checked_sub(fs.sizes()[FileSystem::ROOT])
+        .ok_or_else(|| AocError::invalid_state("The disk holds more than its capacity"))?;
+
+    Ok(folders_freeing(fs, wanted.saturating_sub(free_space)))
+}
+
+/// The folders holding at least `bytes`, smallest first, with their sizes
+pub fn folders_freeing(fs: &FileSystem, bytes: usize) -> Vec<(NodeId, usize)> {
+    let sizes = fs.sizes();
+    let mut candidates = fs
+        .folders()
+        .map(|id| (id, sizes[id]))
+        .filter(|(_, size)| *size >= bytes)
+        .collect::<Vec<_>>();
+    candidates.sort_by_key(|(_, size)| *size);
+
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filesystem() -> FileSystem {
+        let mut fs = FileSystem::new();
+        let a = fs.mkdir(FileSystem::ROOT, "a").unwrap();
+        let e = fs.mkdir(a, "e").unwrap();
+        fs.touch(e, "i", 584).unwrap();
+        fs.touch(a, "f", 29116).unwrap();
+        fs.touch(FileSystem::ROOT, "b.txt", 14848514).unwrap();
+        fs
+    }
+
+    fn run(query: &str) -> String {
+        query.parse::<Query>().unwrap().run(&filesystem()).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("du -d 1".parse(), Ok(Query::Du(Some(1))));
+        assert_eq!("  find -size +100 ".parse(), Ok(Query::Find(100)));
+        assert_eq!(
+            "ls".parse::<Query>().unwrap_err().to_string(),
+            "Parse error: Unknown query 'ls', try tree, du [-d N], find -size +N or free N"
+        );
+        assert!("find -size 100".parse::<Query>().is_err());
+        assert!("du -d x".parse::<Query>().is_err());
+    }
+
+    #[test]
+    fn test_tree() {
+        assert_eq!(
+            run("tree"),
+            "- / (dir, size=14878214)\n\
+             \x20 - a (dir, size=29700)\n\
+             \x20   - e (dir, size=584)\n\
+             \x20     - i (file, size=584)\n\
+             \x20   - f (file, size=29116)\n\
+             \x20 - b.txt (file, size=14848514)\n"
+        );
+    }
+
+    #[test]
+    fn test_du() {
+        assert_eq!(run("du"), "584\t/a/e\n29700\t/a\n14878214\t/\n");
+        assert_eq!(run("du -d 1"), "29700\t/a\n14878214\t/\n");
+        assert_eq!(run("du -d 0"), "14878214\t/\n");
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(run("find -size +584"), "/a/f\n/b.txt\n");
+        assert_eq!(run("find -size +20000000"), "");
+    }
+
+    #[test]
+    fn test_free() {
+        assert_eq!(run("free 600"), "29700\t/a\n14878214\t/\n");
+        assert_eq!(run("free 584"), "584\t/a/e\n29700\t/a\n14878214\t/\n");
+        assert_eq!(run("free 20000000"), "");
+    }
+
+    #[test]
+    fn test_deletion_candidates() {
+        let fs = filesystem();
+        // 121786 bytes are free already, so /a/e isn't enough to reach 130000
+        let candidates = deletion_candidates(&fs, 15000000, 130000).unwrap();
+        assert_eq!(
+            candidates.iter().map(|(_, size)| *size).collect::<Vec<_>>(),
+            vec![29700, 14878214]
+        );
+        assert!(deletion_candidates(&fs, 100, 1).is_err());
+    }
+}
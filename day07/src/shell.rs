@@ -0,0 +1,85 @@
+use std::str::FromStr;
+
+use common::AocError;
+
+// One line of the transcript: a command, or a line printed by `ls`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Line {
+    Cd(String),
+    // lists the current folder when there's no path
+    Ls(Option<String>),
+    Dir(String),
+    File(String, usize),
+}
+
+impl FromStr for Line {
+    type Err = AocError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        if let Some(command) = line.strip_prefix('$') {
+            let mut words = command.split_whitespace();
+            let name = words
+                .next()
+                .ok_or_else(|| AocError::parse("Missing command after '$'"))?;
+            if !["cd", "ls"].contains(&name) {
+                return Err(AocError::parse(format!("Unknown command '{}'", name)));
+            }
+            let path = words.next().map(str::to_string);
+            if words.next().is_some() {
+                return Err(AocError::parse(format!("Too many arguments to {}", name)));
+            }
+
+            return match name {
+                "cd" => path
+                    .map(Line::Cd)
+                    .ok_or_else(|| AocError::parse("cd needs a folder to go to")),
+                _ => Ok(Line::Ls(path)),
+            };
+        }
+
+        match line.split_once(' ') {
+            Some(("dir", name)) if !name.is_empty() => Ok(Line::Dir(name.to_string())),
+            Some((size, name)) if !name.is_empty() => {
+                Ok(Line::File(name.to_string(), size.parse()?))
+            }
+            _ => Err(AocError::parse(format!("Invalid ls output '{}'", line))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(
+            "$ cd a/b/../c".parse(),
+            Ok(Line::Cd("a/b/../c".to_string()))
+        );
+        assert_eq!("$ ls".parse(), Ok(Line::Ls(None)));
+        assert_eq!("$ ls /a".parse(), Ok(Line::Ls(Some("/a".to_string()))));
+    }
+
+    #[test]
+    fn test_parse_output() {
+        assert_eq!("dir e".parse(), Ok(Line::Dir("e".to_string())));
+        assert_eq!("584 i".parse(), Ok(Line::File("i".to_string(), 584)));
+    }
+
+    #[test]
+    fn test_parse_invalid_lines() {
+        let error = |line: &str| line.parse::<Line>().unwrap_err().to_string();
+        assert_eq!(error("$ rm -rf /"), "Parse error: Unknown command 'rm'");
+        assert_eq!(error("$ cd a b"), "Parse error: Too many arguments to cd");
+        assert_eq!(error("$ rm x"), "Parse error: Unknown command 'rm'");
+        assert_eq!(error("$ cd"), "Parse error: cd needs a folder to go to");
+        assert_eq!(error("$"), "Parse error: Missing command after '$'");
+        assert_eq!(
+            error("12k a.txt"),
+            "Parse error: invalid digit found in string"
+        );
+        assert_eq!(error("a.txt"), "Parse error: Invalid ls output 'a.txt'");
+        assert_eq!(error("dir "), "Parse error: Invalid ls output 'dir '");
+    }
+}
@@ -0,0 +1,243 @@
+use std::str::FromStr;
+
+use common::{AocError, AocResult, Matrix2D, PartResult, Solution};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = TreeMap;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        input.parse()
+    }
+
+    fn part_1(&self, treemap: &Self::Input) -> PartResult<usize> {
+        Ok(part_1(treemap))
+    }
+
+    fn part_2(&self, treemap: &Self::Input) -> PartResult<usize> {
+        Ok(part_2(treemap))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TreeMap {
+    heights: Matrix2D<u8>,
+}
+
+#[derive(Debug, Clone)]
+struct Tree {
+    visible: bool,
+    scenic_score: usize,
+}
+
+impl FromStr for TreeMap {
+    type Err = AocError;
+
+    fn from_str(input: &str) -> Result<TreeMap, AocError> {
+        let heights: Vec<Vec<u8>> = input
+            .lines()
+            .enumerate()
+            .map(|(row, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(col, c)| {
+                        c.to_digit(10).map(|h| h as u8).ok_or_else(|| {
+                            AocError::parse(format!("Invalid tree height '{}'", c))
+                                .at_column(col + 1)
+                                .at_line(row + 1)
+                        })
+                    })
+                    .collect()
+            })
+            .collect::<AocResult<_>>()?;
+
+        let cols = heights.first().map_or(0, Vec::len);
+        if cols == 0 {
+            return Err(AocError::parse("The map is empty"));
+        }
+        if let Some(row) = heights.iter().position(|row| row.len() != cols) {
+            return Err(AocError::parse(format!("Expected {} trees", cols)).at_line(row + 1));
+        }
+
+        Ok(TreeMap {
+            heights: heights.into(),
+        })
+    }
+}
+
+// Looks back from every tree of a line towards the line's start. A stack keeps
+// the trees that can still block the view, so each tree is pushed and popped
+// at most once.
+fn sweep(line: impl Iterator<Item = ((usize, usize), u8)>, trees: &mut Matrix2D<Tree>) {
+    let mut blockers: Vec<(usize, u8)> = vec![];
+
+    for (position, (coords, height)) in line.enumerate() {
+        while blockers.last().is_some_and(|(_, h)| *h < height) {
+            blockers.pop();
+        }
+
+        let tree = &mut trees[coords];
+        match blockers.last() {
+            None => {
+                tree.visible = true;
+                tree.scenic_score *= position;
+            }
+            Some((blocker, _)) => tree.scenic_score *= position - blocker,
+        }
+
+        blockers.push((position, height));
+    }
+}
+
+impl TreeMap {
+    fn survey(&self) -> Matrix2D<Tree> {
+        let heights = &self.heights;
+        let mut trees = heights.map(|_| Tree {
+            visible: false,
+            scenic_score: 1,
+        });
+
+        for row in 0..heights.rows() {
+            let line = || {
+                heights
+                    .row(row)
+                    .iter()
+                    .enumerate()
+                    .map(move |(col, height)| ((row, col), *height))
+            };
+            sweep(line(), &mut trees);
+            sweep(line().rev(), &mut trees);
+        }
+
+        for col in 0..heights.cols() {
+            let line = || {
+                heights
+                    .col(col)
+                    .enumerate()
+                    .map(move |(row, height)| ((row, col), *height))
+            };
+            sweep(line(), &mut trees);
+            sweep(line().rev(), &mut trees);
+        }
+
+        trees
+    }
+}
+
+fn part_1(treemap: &TreeMap) -> usize {
+    treemap.survey().iter().filter(|t| t.visible).count()
+}
+
+fn part_2(treemap: &TreeMap) -> usize {
+    treemap
+        .survey()
+        .iter()
+        .map(|t| t.scenic_score)
+        .max()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+    const TEST_INPUT: &str = r#"30373
+25512
+65332
+33549
+35390"#;
+
+    #[test]
+    fn test_sweep() {
+        let heights: Matrix2D<u8> = vec![vec![3, 0, 3, 7, 3, 1]].into();
+        let mut trees = heights.map(|_| Tree {
+            visible: false,
+            scenic_score: 1,
+        });
+        sweep(
+            heights
+                .row(0)
+                .iter()
+                .enumerate()
+                .map(|(col, h)| ((0, col), *h)),
+            &mut trees,
+        );
+
+        assert_eq!(
+            trees.iter().map(|t| t.visible).collect::<Vec<_>>(),
+            vec![true, false, false, true, false, false]
+        );
+        assert_eq!(
+            trees.iter().map(|t| t.scenic_score).collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 1, 1]
+        );
+    }
+
+    #[test]
+    fn test_calculate_visibility() {
+        let treemap: TreeMap = TEST_INPUT.parse().unwrap();
+        let trees = treemap.survey();
+
+        assert!(trees[(0, 0)].visible);
+        assert!(trees[(1, 1)].visible);
+        assert!(trees[(2, 1)].visible);
+        assert!(!trees[(1, 3)].visible);
+        assert!(!trees[(2, 2)].visible);
+    }
+
+    #[test]
+    fn test_part_1() {
+        let treemap = TEST_INPUT.parse().unwrap();
+        let visibles = part_1(&treemap);
+        assert_eq!(visibles, 21);
+    }
+
+    #[test]
+    fn test_calculate_scenic_score() {
+        let treemap: TreeMap = TEST_INPUT.parse().unwrap();
+        let trees = treemap.survey();
+
+        assert_eq!(trees[(1, 2)].scenic_score, 4);
+        assert_eq!(trees[(3, 2)].scenic_score, 8);
+        assert_eq!(trees[(0, 0)].scenic_score, 0);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let treemap: TreeMap = TEST_INPUT.parse().unwrap();
+        let max_scenic_score = part_2(&treemap);
+        assert_eq!(max_scenic_score, 8);
+    }
+
+    #[test]
+    fn test_parse_invalid_map() {
+        let error = "303\n2x5".parse::<TreeMap>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Parse error at 2:2: Invalid tree height 'x'"
+        );
+        assert!("303\n25".parse::<TreeMap>().is_err());
+        assert!("".parse::<TreeMap>().is_err());
+    }
+
+    #[test]
+    fn test_full_part_1() {
+        let input = common::input::from_path(INPUT_PATH).unwrap();
+        let treemap = input.parse().unwrap();
+        let visibles = part_1(&treemap);
+        assert_eq!(visibles, 1787);
+    }
+
+    #[test]
+    fn test_full_part_2() {
+        let input = common::input::from_path(INPUT_PATH).unwrap();
+        let treemap: TreeMap = input.parse().unwrap();
+        let max_scenic_score = part_2(&treemap);
+        assert_eq!(max_scenic_score, 440640);
+    }
+}
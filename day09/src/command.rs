@@ -1,20 +1,14 @@
-#[derive(Debug, PartialEq)]
-pub enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
+use std::str::FromStr;
 
-impl From<&str> for Direction {
-    fn from(dir: &str) -> Self {
-        match dir {
-            "U" => Self::Up,
-            "D" => Self::Down,
-            "L" => Self::Left,
-            "R" => Self::Right,
-            _ => panic!("Invalid direction"),
-        }
+use common::{AocError, AocResult, Direction};
+
+fn parse_direction(dir: &str) -> AocResult<Direction> {
+    match dir {
+        "U" => Ok(Direction::Up),
+        "D" => Ok(Direction::Down),
+        "L" => Ok(Direction::Left),
+        "R" => Ok(Direction::Right),
+        _ => Err(AocError::parse(format!("Invalid direction '{}'", dir))),
     }
 }
 
@@ -33,15 +27,22 @@ impl Command {
     }
 }
 
-impl From<&str> for Command {
-    fn from(str: &str) -> Self {
-        let (dir, distance) = str.split_once(" ").unwrap();
-        Command::new(dir.into(), distance.parse().unwrap())
+impl FromStr for Command {
+    type Err = AocError;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        let (dir, distance) = str
+            .split_once(' ')
+            .ok_or_else(|| AocError::parse(format!("Invalid command '{}'", str)))?;
+        let distance = distance
+            .parse()
+            .map_err(|e| AocError::from(e).at_column(dir.len() + 2))?;
+        Ok(Command::new(parse_direction(dir)?, distance))
     }
 }
 
-pub fn parse_commands(input: &str) -> Vec<Command> {
-    input.lines().map(Into::into).collect()
+pub fn parse_commands(input: &str) -> AocResult<Vec<Command>> {
+    common::error::parse_lines(input)
 }
 
 #[cfg(test)]
@@ -51,7 +52,7 @@ mod tests {
 
     #[test]
     fn test_parse_commands() {
-        let commands = parse_commands(TEST_INPUT);
+        let commands = parse_commands(TEST_INPUT).unwrap();
         assert_eq!(commands.len(), 8);
         assert_eq!(
             commands[0],
@@ -61,4 +62,16 @@ mod tests {
             }
         )
     }
+
+    #[test]
+    fn test_parse_invalid_commands() {
+        assert_eq!(
+            parse_commands("R 4\nX 4").unwrap_err().to_string(),
+            "Parse error at line 2: Invalid direction 'X'"
+        );
+        assert_eq!(
+            parse_commands("R 4\nU -4").unwrap_err().to_string(),
+            "Parse error at 2:3: invalid digit found in string"
+        );
+    }
 }
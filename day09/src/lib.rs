@@ -0,0 +1,64 @@
+use std::collections::HashSet;
+
+mod command;
+mod ropes;
+
+use command::Command;
+use common::{AocResult, PartResult, Solution};
+
+use ropes::*;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Command>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        command::parse_commands(input)
+    }
+
+    fn part_1(&self, commands: &Self::Input) -> PartResult<usize> {
+        Ok(part_1(commands))
+    }
+
+    fn part_2(&self, commands: &Self::Input) -> PartResult<usize> {
+        Ok(part_2(commands))
+    }
+}
+
+fn common_part(mut rope: impl Rope, commands: &[Command]) -> usize {
+    commands
+        .iter()
+        .flat_map(|command| rope.execute_command(command).into_iter())
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+fn part_1(commands: &[Command]) -> usize {
+    common_part(SimpleRope::default(), commands)
+}
+
+fn part_2(commands: &[Command]) -> usize {
+    common_part(ComplexRope::default(), commands)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PART_1_TEST_INPUT: &str = "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2";
+    const PART_2_TEST_INPUT: &str = "R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20";
+    #[test]
+    fn test_part_1() {
+        let result = part_1(&command::parse_commands(PART_1_TEST_INPUT).unwrap());
+        assert_eq!(result, 13)
+    }
+
+    #[test]
+    fn test_part_2() {
+        let result = part_2(&command::parse_commands(PART_2_TEST_INPUT).unwrap());
+        assert_eq!(result, 36)
+    }
+}
@@ -1,10 +1,18 @@
 use std::collections::HashSet;
 
 use crate::command::Command;
-use crate::point::Point;
+
+type Point = common::Point<isize>;
 
 pub type VisitedPositions = HashSet<Point>;
 
+// a knot that is no longer touching the one ahead moves one step towards it
+fn follow(knot: &mut Point, leader: Point) {
+    if knot.chebyshev_distance(&leader) > 1 {
+        *knot += (leader - *knot).signum();
+    }
+}
+
 pub struct SimpleRope {
     pub head: Point,
     pub tail: Point,
@@ -13,8 +21,8 @@ pub struct SimpleRope {
 impl Default for SimpleRope {
     fn default() -> Self {
         Self {
-            head: Point(0, 0),
-            tail: Point(0, 0),
+            head: Point::new(0, 0),
+            tail: Point::new(0, 0),
         }
     }
 }
@@ -28,10 +36,8 @@ impl Rope for SimpleRope {
         let mut visited_positions = VisitedPositions::new();
 
         for _ in 0..command.distance {
-            self.head.move_one(&command.direction);
-            if self.head.distance_from(&self.tail) >= 2.0 {
-                self.tail.get_closer_to(&self.head)
-            }
+            self.head += command.direction;
+            follow(&mut self.tail, self.head);
 
             visited_positions.insert(self.tail);
         }
@@ -47,7 +53,7 @@ pub struct ComplexRope {
 impl Default for ComplexRope {
     fn default() -> Self {
         Self {
-            rope: [Point::default(); 10],
+            rope: [Point::new(0, 0); 10],
         }
     }
 }
@@ -57,13 +63,10 @@ impl Rope for ComplexRope {
         let mut visited_positions = VisitedPositions::new();
 
         for _ in 0..command.distance {
-            self.rope[0].move_one(&command.direction);
+            self.rope[0] += command.direction;
             for i in 1..=9 {
-                let reference = &self.rope[i - 1].clone();
-                let current = &mut self.rope[i];
-                if current.distance_from(&reference) >= 2.0 {
-                    current.get_closer_to(&reference);
-                }
+                let leader = self.rope[i - 1];
+                follow(&mut self.rope[i], leader);
             }
             visited_positions.insert(self.rope[9]);
         }
@@ -76,22 +79,43 @@ impl Rope for ComplexRope {
 mod tests {
     use super::*;
     use crate::command::Command;
-    use crate::command::Direction;
+    use common::Direction;
+
+    macro_rules! test_follow {
+        (($x1:literal, $y1:literal) -> ($x2:literal, $y2:literal) goes to ($r1:literal, $r2:literal)) => {
+            let mut knot = Point::new($x1, $y1);
+            follow(&mut knot, Point::new($x2, $y2));
+            assert_eq!(knot, Point::new($r1, $r2))
+        };
+    }
+
+    #[test]
+    fn test_follow() {
+        test_follow!((0, 0) -> (1, 0) goes to (0, 0));
+        test_follow!((0, 0) -> (1, 1) goes to (0, 0));
+        test_follow!((0, 0) -> (2, 0) goes to (1, 0));
+        test_follow!((0, 0) -> (2, 1) goes to (1, 1));
+        test_follow!((0, 0) -> (1, 2) goes to (1, 1));
+        test_follow!((0, 0) -> (-1, 2) goes to (-1, 1));
+        test_follow!((0, 0) -> (-2, 1) goes to (-1, 1));
+        test_follow!((0, 0) -> (-2, 0) goes to (-1, 0));
+        test_follow!((0, 0) -> (2, 2) goes to (1, 1));
+    }
 
     #[test]
     fn test_execute_command_no_tail_move() {
         let mut rope = SimpleRope::default();
         rope.execute_command(&Command::new(Direction::Up, 1));
 
-        assert_eq!(rope.head, Point(0, -1));
-        assert_eq!(rope.tail, Point(0, 0));
+        assert_eq!(rope.head, Point::new(0, -1));
+        assert_eq!(rope.tail, Point::new(0, 0));
     }
 
     #[test]
     fn test_execute_command_tails_follows_in_the_same_direction() {
         let mut rope = SimpleRope::default();
         rope.execute_command(&Command::new(Direction::Up, 2));
-        assert_eq!(rope.head, Point(0, -2));
-        assert_eq!(rope.tail, Point(0, -1));
+        assert_eq!(rope.head, Point::new(0, -2));
+        assert_eq!(rope.tail, Point::new(0, -1));
     }
 }
@@ -0,0 +1,178 @@
+use std::str::FromStr;
+
+use common::{AocError, AocResult, PartResult, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+    type Output1 = isize;
+    type Output2 = String;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        parse_program(input)
+    }
+
+    fn part_1(&self, program: &Self::Input) -> PartResult<isize> {
+        Ok(part_1(program))
+    }
+
+    fn part_2(&self, program: &Self::Input) -> PartResult<String> {
+        part_2(program)
+    }
+}
+
+#[derive(Debug)]
+pub enum Instruction {
+    Noop,
+    AddX(isize),
+}
+
+impl FromStr for Instruction {
+    type Err = AocError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        match line.split_once(' ') {
+            None if line == "noop" => Ok(Instruction::Noop),
+            Some(("addx", amount)) => amount
+                .parse()
+                .map(Instruction::AddX)
+                .map_err(|e| AocError::from(e).at_column(6)),
+            _ => Err(AocError::parse(format!("Unknown instruction '{}'", line))),
+        }
+    }
+}
+
+fn parse_program(input: &str) -> AocResult<Vec<Instruction>> {
+    common::error::parse_lines(input)
+}
+
+struct Cpu {
+    x: isize,
+    cycles_history: Vec<isize>,
+}
+
+impl Cpu {
+    fn execute(&mut self, instructions: &[Instruction]) {
+        instructions
+            .iter()
+            .for_each(|instruction| match instruction {
+                Instruction::Noop => self.noop(),
+                Instruction::AddX(amount) => self.add_x(*amount),
+            });
+        self.noop();
+    }
+
+    fn new() -> Self {
+        Self {
+            x: 1,
+            cycles_history: Vec::new(),
+        }
+    }
+
+    fn noop(&mut self) {
+        self.cycles_history.push(self.x);
+    }
+
+    fn add_x(&mut self, x: isize) {
+        self.noop();
+        self.noop();
+        self.x += x;
+    }
+
+    fn crt(&self) -> AocResult<String> {
+        let mut crt = [['.'; 40]; 6];
+        for (row, crt_row) in crt.iter_mut().enumerate() {
+            for (clock_cycle, pixel) in crt_row.iter_mut().enumerate() {
+                let value = self
+                    .cycles_history
+                    .get(row * 40 + clock_cycle)
+                    .ok_or_else(|| AocError::invalid_state("Too few cycles to draw the CRT"))?;
+                if (value - 1..=value + 1).contains(&(clock_cycle as isize)) {
+                    *pixel = '#'
+                }
+            }
+        }
+
+        Ok(crt
+            .iter()
+            .map(|row| row.iter().collect())
+            .collect::<Vec<String>>()
+            .join("\n"))
+    }
+}
+
+fn part_1(program: &[Instruction]) -> isize {
+    let mut cpu = Cpu::new();
+    cpu.execute(program);
+
+    cpu.cycles_history
+        .iter()
+        .enumerate()
+        .skip(19)
+        .step_by(40)
+        .map(|(step, value)| value * ((step + 1) as isize))
+        .sum()
+}
+
+fn part_2(program: &[Instruction]) -> AocResult<String> {
+    let mut cpu = Cpu::new();
+    cpu.execute(program);
+    cpu.crt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MINIMAL_TEST_INPUT: &str = "noop\naddx 3\naddx -5";
+    const TEST_INPUT: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn test_cpu_noop() {
+        let mut cpu = Cpu::new();
+        cpu.noop();
+        assert_eq!(cpu.x, 1);
+        assert_eq!(cpu.cycles_history.len(), 1)
+    }
+
+    #[test]
+    fn test_cpu_add_x() {
+        let mut cpu = Cpu::new();
+        cpu.add_x(3);
+        assert_eq!(cpu.x, 4);
+        assert_eq!(cpu.cycles_history, vec![1, 1]);
+    }
+
+    #[test]
+    fn test_minimal_input() {
+        let mut cpu = Cpu::new();
+        cpu.execute(&parse_program(MINIMAL_TEST_INPUT).unwrap());
+        assert_eq!(cpu.cycles_history, vec![1, 1, 1, 4, 4, -1])
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(&parse_program(TEST_INPUT).unwrap()), 13140)
+    }
+
+    const EXPECTED_PART_2: &str = "##..##..##..##..##..##..##..##..##..##..\n###...###...###...###...###...###...###.\n####....####....####....####....####....\n#####.....#####.....#####.....#####.....\n######......######......######......####\n#######.......#######.......#######.....";
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            part_2(&parse_program(TEST_INPUT).unwrap()).unwrap(),
+            EXPECTED_PART_2
+        )
+    }
+
+    #[test]
+    fn test_invalid_program() {
+        assert_eq!(
+            parse_program("noop\naddx x").unwrap_err().to_string(),
+            "Parse error at 2:6: invalid digit found in string"
+        );
+        assert!(parse_program("noop\nsubx 3").is_err());
+        assert!(part_2(&parse_program(MINIMAL_TEST_INPUT).unwrap()).is_err());
+    }
+}
@@ -0,0 +1,78 @@
+use common::{AocError, AocResult, PartResult, Solution};
+
+mod monkee;
+mod operation;
+
+use monkee::Monkee;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Monkee>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        monkee::parse_all(input)
+    }
+
+    fn part_1(&self, monkees: &Self::Input) -> PartResult<usize> {
+        part_1(monkees)
+    }
+
+    fn part_2(&self, monkees: &Self::Input) -> PartResult<usize> {
+        part_2(monkees)
+    }
+}
+
+fn part_1(monkees: &[Monkee]) -> AocResult<usize> {
+    let mut monkees = monkees.to_vec();
+    for _ in 0..20 {
+        monkee::do_a_turn(&mut monkees, |v| v / 3);
+    }
+
+    monkey_business(&monkees)
+}
+
+fn part_2(monkees: &[Monkee]) -> AocResult<usize> {
+    let mut monkees = monkees.to_vec();
+    let worry_divisor: isize = monkees.iter().map(|m| m.test).product();
+
+    for _ in 0..10000 {
+        monkee::do_a_turn(&mut monkees, |f| f % worry_divisor);
+    }
+
+    monkey_business(&monkees)
+}
+
+fn monkey_business(monkees: &[Monkee]) -> AocResult<usize> {
+    let mut inspected_items: Vec<_> = monkees.iter().map(|m| m.inspected_items).collect();
+    inspected_items.sort_by(|a, b| b.cmp(a));
+    match inspected_items[..] {
+        [first, second, ..] => Ok(first * second),
+        _ => Err(AocError::no_solution("At least two monkeys are needed")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            part_1(&monkee::parse_all(TEST_INPUT).unwrap()).unwrap(),
+            10605
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            part_2(&monkee::parse_all(TEST_INPUT).unwrap()).unwrap(),
+            2713310158
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use crate::operation::Operation;
-use std::error::Error;
+use common::{AocError, AocResult};
 
+#[derive(Debug, Clone)]
 pub struct Monkee {
     items: Vec<isize>,
     operation: Operation,
@@ -10,7 +11,7 @@ pub struct Monkee {
     pub inspected_items: usize,
 }
 
-pub fn do_a_turn<F>(monkees: &mut Vec<Monkee>, worry_decrease_fn: F)
+pub fn do_a_turn<F>(monkees: &mut [Monkee], worry_decrease_fn: F)
 where
     F: Fn(isize) -> isize,
 {
@@ -36,40 +37,73 @@ where
 }
 
 impl TryFrom<&[&str]> for Monkee {
-    type Error = Box<dyn Error>;
+    type Error = AocError;
     fn try_from(data: &[&str]) -> Result<Self, Self::Error> {
-        let items = data[1]
-            .trim_start_matches("  Starting items: ")
-            .split(", ")
-            .map(|item| item.parse().map_err(|_| "Invalid number"))
-            .collect::<Result<_, _>>()?;
-        let operation: Operation = data[2].trim_start_matches("  Operation: new = ").into();
-        let test: isize = data[3]
-            .trim_start_matches("  Test: divisible by ")
-            .parse()?;
-        let if_true = data[4]
-            .trim_start_matches("    If true: throw to monkey ")
-            .parse()?;
-        let if_false = data[5]
-            .trim_start_matches("    If false: throw to monkey ")
-            .parse()?;
-        Ok(Monkee {
-            items,
-            operation,
-            test,
-            if_false,
-            if_true,
-            inspected_items: 0,
-        })
+        parse_monkee(data, 1)
     }
 }
 
-pub fn parse_all(input: &str) -> Result<Vec<Monkee>, Box<dyn Error>> {
+// `first_line` is where `data` starts in the whole input, to report errors
+// with the right line number
+fn parse_monkee(data: &[&str], first_line: usize) -> AocResult<Monkee> {
+    let field = |index: usize, prefix: &str| {
+        data.get(index)
+            .and_then(|line| line.strip_prefix(prefix))
+            .ok_or_else(|| {
+                AocError::parse(format!("Expected '{}'", prefix.trim())).at_line(first_line + index)
+            })
+    };
+
+    let items = field(1, "  Starting items: ")?
+        .split(", ")
+        .map(str::parse)
+        .collect::<Result<_, _>>()
+        .map_err(|e| AocError::from(e).at_line(first_line + 1))?;
+    let operation: Operation = field(2, "  Operation: new = ")?
+        .parse()
+        .map_err(|e: AocError| e.at_line(first_line + 2))?;
+    let test: isize = field(3, "  Test: divisible by ")?
+        .parse()
+        .map_err(|e| AocError::from(e).at_line(first_line + 3))?;
+    if test == 0 {
+        return Err(AocError::parse("Can't test divisibility by 0").at_line(first_line + 3));
+    }
+    let if_true = field(4, "    If true: throw to monkey ")?
+        .parse()
+        .map_err(|e| AocError::from(e).at_line(first_line + 4))?;
+    let if_false = field(5, "    If false: throw to monkey ")?
+        .parse()
+        .map_err(|e| AocError::from(e).at_line(first_line + 5))?;
+    Ok(Monkee {
+        items,
+        operation,
+        test,
+        if_false,
+        if_true,
+        inspected_items: 0,
+    })
+}
+
+pub fn parse_all(input: &str) -> AocResult<Vec<Monkee>> {
     let lines: Vec<_> = input.lines().collect();
-    lines
+    let monkees = lines
         .chunks(7)
-        .map(|chunk| chunk.try_into())
-        .collect::<Result<Vec<Monkee>, Box<dyn Error>>>()
+        .enumerate()
+        .map(|(index, chunk)| parse_monkee(chunk, index * 7 + 1))
+        .collect::<AocResult<Vec<Monkee>>>()?;
+
+    for monkee in &monkees {
+        let target = monkee.if_true.max(monkee.if_false);
+        if target >= monkees.len() {
+            return Err(AocError::parse(format!(
+                "Can't throw to monkey {}, there are only {}",
+                target,
+                monkees.len()
+            )));
+        }
+    }
+
+    Ok(monkees)
 }
 
 #[cfg(test)]
@@ -93,6 +127,18 @@ mod tests {
         assert_eq!(monkee.if_false, 3);
     }
 
+    #[test]
+    fn test_parse_invalid_monkeys() {
+        let input = TEST_INPUT.replacen("old * 19", "old / 19", 1);
+        assert_eq!(
+            parse_all(&input).unwrap_err().to_string(),
+            "Parse error at line 3: Invalid operation 'old / 19'"
+        );
+
+        let input = TEST_INPUT.replacen("throw to monkey 3", "throw to monkey 9", 1);
+        assert!(parse_all(&input).is_err());
+    }
+
     #[test]
     fn test_parse_all_monkeys() {
         let monkeys = parse_all(TEST_INPUT).unwrap();
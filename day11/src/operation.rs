@@ -1,15 +1,23 @@
-#[derive(Debug, PartialEq)]
+use std::str::FromStr;
+
+use common::AocError;
+
+#[derive(Debug, PartialEq, Clone)]
 pub enum Item {
     Current,
     Constant(isize),
 }
 
-impl From<&str> for Item {
-    fn from(item: &str) -> Self {
+impl FromStr for Item {
+    type Err = AocError;
+
+    fn from_str(item: &str) -> Result<Self, Self::Err> {
         if item == "old" {
-            Self::Current
+            Ok(Self::Current)
         } else {
-            Self::Constant(item.parse().expect("Invalid value"))
+            item.parse()
+                .map(Self::Constant)
+                .map_err(|_| AocError::parse(format!("Invalid value '{}'", item)))
         }
     }
 }
@@ -23,20 +31,22 @@ impl Item {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum Operation {
     Add(Item, Item),
     Mult(Item, Item),
 }
 
-impl From<&str> for Operation {
-    fn from(op: &str) -> Self {
-        if op.contains("*") {
-            let (left, right) = op.split_once(" * ").unwrap();
-            Operation::Mult(left.into(), right.into())
+impl FromStr for Operation {
+    type Err = AocError;
+
+    fn from_str(op: &str) -> Result<Self, Self::Err> {
+        if let Some((left, right)) = op.split_once(" * ") {
+            Ok(Operation::Mult(left.parse()?, right.parse()?))
+        } else if let Some((left, right)) = op.split_once(" + ") {
+            Ok(Operation::Add(left.parse()?, right.parse()?))
         } else {
-            let (left, right) = op.split_once(" + ").unwrap();
-            Operation::Add(left.into(), right.into())
+            Err(AocError::parse(format!("Invalid operation '{}'", op)))
         }
     }
 }
@@ -57,3 +67,22 @@ impl Operation {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_operation() {
+        assert_eq!(
+            "old * old".parse(),
+            Ok(Operation::Mult(Item::Current, Item::Current))
+        );
+        assert_eq!(
+            "old + 6".parse(),
+            Ok(Operation::Add(Item::Current, Item::Constant(6)))
+        );
+        assert!("old - 6".parse::<Operation>().is_err());
+        assert!("old + new".parse::<Operation>().is_err());
+    }
+}
@@ -0,0 +1,244 @@
+use std::thread;
+
+use common::pathfinding::{grid_bfs, Cell, Connectivity, Path};
+use common::{Alternative, AocError, AocResult, Matrix2D, Part, PartResult, Solution};
+
+pub mod render;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Matrix2D<Location>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, map: &Self::Input) -> PartResult<usize> {
+        part_1(map)
+    }
+
+    fn part_2(&self, map: &Self::Input) -> PartResult<usize> {
+        part_2(map)
+    }
+
+    fn alternatives(&self) -> Vec<Alternative<Self>> {
+        vec![Alternative::new("part_2_parallel", Part::Two, |_, map| {
+            part_2_parallel(map).map(|steps| steps.to_string())
+        })]
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum LocationKind {
+    Start,
+    End,
+    Normal,
+}
+
+#[derive(Debug, Clone)]
+pub struct Location {
+    height: usize,
+    kind: LocationKind,
+}
+
+impl TryFrom<char> for Location {
+    type Error = AocError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        let (height, kind) = match c {
+            'S' => (1, LocationKind::Start),
+            'E' => (26, LocationKind::End),
+            'a'..='z' => (c as usize - 'a' as usize + 1, LocationKind::Normal),
+            _ => return Err(AocError::parse(format!("Invalid height '{}'", c))),
+        };
+        Ok(Location { height, kind })
+    }
+}
+
+impl Location {
+    fn symbol(&self) -> char {
+        match self.kind {
+            LocationKind::Start => 'S',
+            LocationKind::End => 'E',
+            LocationKind::Normal => (b'a' + self.height as u8 - 1) as char,
+        }
+    }
+}
+
+fn parse_input(input: &str) -> AocResult<Matrix2D<Location>> {
+    let rows = input
+        .lines()
+        .enumerate()
+        .map(|(row, line)| {
+            line.chars()
+                .enumerate()
+                .map(|(col, c)| {
+                    Location::try_from(c).map_err(|e| e.at_column(col + 1).at_line(row + 1))
+                })
+                .collect::<AocResult<Vec<_>>>()
+        })
+        .collect::<AocResult<Vec<_>>>()?;
+
+    let cols = rows.first().map_or(0, Vec::len);
+    if cols == 0 {
+        return Err(AocError::parse("The map is empty"));
+    }
+    if let Some(row) = rows.iter().position(|row| row.len() != cols) {
+        return Err(AocError::parse(format!("Expected {} columns", cols)).at_line(row + 1));
+    }
+
+    let map: Matrix2D<Location> = rows.into();
+    for kind in [LocationKind::Start, LocationKind::End] {
+        if map.find_all(|l| l.kind == kind).len() != 1 {
+            return Err(AocError::parse(format!(
+                "Expected exactly one {:?} location",
+                kind
+            )));
+        }
+    }
+
+    Ok(map)
+}
+
+fn climbable(from: &Location, to: &Location) -> bool {
+    to.height <= from.height + 1
+}
+
+fn find_kind(map: &Matrix2D<Location>, kind: LocationKind) -> AocResult<(usize, usize)> {
+    map.find(|l| l.kind == kind)
+        .ok_or_else(|| AocError::invalid_state(format!("No {:?} location", kind)))
+}
+
+fn shortest_climb(
+    map: &Matrix2D<Location>,
+    starts: impl IntoIterator<Item = Cell>,
+) -> AocResult<Path<Cell>> {
+    let end = find_kind(map, LocationKind::End)?;
+    grid_bfs(map, Connectivity::Four, starts, climbable)
+        .path_to(&end)
+        .ok_or_else(|| AocError::no_solution("The destination can't be reached"))
+}
+
+/// The shortest route from `S` to `E`
+pub fn climb_route(map: &Matrix2D<Location>) -> AocResult<Path<Cell>> {
+    shortest_climb(map, [find_kind(map, LocationKind::Start)?])
+}
+
+/// The shortest route to `E` starting from any of the lowest locations
+pub fn best_route(map: &Matrix2D<Location>) -> AocResult<Path<Cell>> {
+    shortest_climb(map, map.find_all(|l| l.height == 1))
+}
+
+fn part_1(map: &Matrix2D<Location>) -> AocResult<usize> {
+    climb_route(map).map(|route| route.steps())
+}
+
+fn part_2(map: &Matrix2D<Location>) -> AocResult<usize> {
+    best_route(map).map(|route| route.steps())
+}
+
+// this is just for fun
+fn part_2_parallel(input: &Matrix2D<Location>) -> AocResult<usize> {
+    let starting_positions = input.find_all(|l| l.height == 1);
+    let mut threads = vec![];
+
+    for start in starting_positions {
+        let input = input.clone();
+        threads.push(thread::spawn(move || {
+            shortest_climb(&input, [start])
+                .ok()
+                .map(|route| route.steps())
+        }));
+    }
+
+    threads
+        .into_iter()
+        .map(|t| {
+            t.join()
+                .map_err(|_| AocError::invalid_state("A search thread panicked"))
+        })
+        .collect::<AocResult<Vec<_>>>()?
+        .into_iter()
+        .flatten()
+        .min()
+        .ok_or_else(|| AocError::no_solution("The destination can't be reached"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi";
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(&parse_input(TEST_INPUT).unwrap()).unwrap(), 31)
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2(&parse_input(TEST_INPUT).unwrap()).unwrap(), 29)
+    }
+
+    #[test]
+    fn test_part_2_parallel() {
+        assert_eq!(
+            part_2_parallel(&parse_input(TEST_INPUT).unwrap()).unwrap(),
+            29
+        )
+    }
+
+    fn assert_valid_route(map: &Matrix2D<Location>, route: &Path<Cell>) {
+        assert_eq!(map[*route.end().unwrap()].kind, LocationKind::End);
+        assert!(route.nodes.windows(2).all(|step| {
+            Connectivity::Four.distance(step[0], step[1]) == 1
+                && climbable(&map[step[0]], &map[step[1]])
+        }));
+    }
+
+    #[test]
+    fn test_climb_route() {
+        let map = parse_input(TEST_INPUT).unwrap();
+        let route = climb_route(&map).unwrap();
+        assert_eq!(route.steps(), 31);
+        assert_eq!(route.start(), Some(&(0, 0)));
+        assert_valid_route(&map, &route);
+    }
+
+    #[test]
+    fn test_best_route() {
+        let map = parse_input(TEST_INPUT).unwrap();
+        let route = best_route(&map).unwrap();
+        assert_eq!(route.steps(), 29);
+        assert_eq!(map[*route.start().unwrap()].height, 1);
+        assert_valid_route(&map, &route);
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let input = parse_input(TEST_INPUT).unwrap();
+        assert_eq!(input.cols(), 8);
+        assert_eq!(input.rows(), 5);
+    }
+
+    #[test]
+    fn test_parse_invalid_input() {
+        assert_eq!(
+            parse_input("Sab\naB7\nbcE").unwrap_err().to_string(),
+            "Parse error at 2:2: Invalid height 'B'"
+        );
+        assert!(parse_input("Sab\nabc").is_err());
+        assert!(parse_input("Sab\nabcE").is_err());
+    }
+
+    #[test]
+    fn test_unreachable_end() {
+        let input = parse_input("Sazz\nazzE").unwrap();
+        assert!(part_1(&input).is_err());
+        assert!(part_2(&input).is_err());
+        assert!(part_2_parallel(&input).is_err());
+    }
+}
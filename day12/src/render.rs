@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+
+use common::pathfinding::{Cell, Path};
+use common::Matrix2D;
+
+use crate::Location;
+
+#[cfg(any(feature = "png_image", feature = "ppm_image"))]
+use common::image_output::color::Color;
+
+// every location is drawn as a square of SCALE x SCALE pixels
+#[cfg(any(feature = "png_image", feature = "ppm_image"))]
+const SCALE: u32 = 4;
+
+// the direction taken when leaving each location of the route
+fn arrows(route: &Path<Cell>) -> HashMap<Cell, char> {
+    route
+        .nodes
+        .windows(2)
+        .map(|step| {
+            let ((row, col), (next_row, next_col)) = (step[0], step[1]);
+            let arrow = if next_row < row {
+                '^'
+            } else if next_row > row {
+                'v'
+            } else if next_col < col {
+                '<'
+            } else {
+                '>'
+            };
+            (step[0], arrow)
+        })
+        .collect()
+}
+
+/// The height map with the route drawn over it as arrows
+pub fn ascii(map: &Matrix2D<Location>, route: &Path<Cell>) -> String {
+    let arrows = arrows(route);
+    (0..map.rows())
+        .map(|row| {
+            (0..map.cols())
+                .map(|col| {
+                    arrows
+                        .get(&(row, col))
+                        .copied()
+                        .unwrap_or_else(|| map[(row, col)].symbol())
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(any(feature = "png_image", feature = "ppm_image"))]
+fn draw<F>(map: &Matrix2D<Location>, route: &Path<Cell>, mut set: F)
+where
+    F: FnMut(u32, u32, Color),
+{
+    use crate::LocationKind;
+
+    let arrows = arrows(route);
+    for row in 0..map.rows() {
+        for col in 0..map.cols() {
+            let location = &map[(row, col)];
+            let color = match location.kind {
+                LocationKind::Start => Color::Cyan,
+                LocationKind::End => Color::Magenta,
+                _ if arrows.contains_key(&(row, col)) => Color::Red,
+                LocationKind::Normal => {
+                    let level = (location.height * 255 / 26) as u8;
+                    Color::Rgb(level / 3, level, level / 3)
+                }
+            };
+            for y in 0..SCALE {
+                for x in 0..SCALE {
+                    set(col as u32 * SCALE + x, row as u32 * SCALE + y, color);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "png_image")]
+pub fn png(
+    map: &Matrix2D<Location>,
+    route: &Path<Cell>,
+    filename: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use common::image_output::png::Image;
+
+    let mut image = Image::new(map.cols() as u32 * SCALE, map.rows() as u32 * SCALE);
+    draw(map, route, |x, y, color| image.set(x, y, color));
+    Ok(image.write_to_file(filename)?)
+}
+
+#[cfg(feature = "ppm_image")]
+pub fn ppm(
+    map: &Matrix2D<Location>,
+    route: &Path<Cell>,
+    filename: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use common::image_output::ppm::Image;
+
+    let mut image = Image::new(map.cols() as u32 * SCALE, map.rows() as u32 * SCALE);
+    draw(map, route, |x, y, color| image.set(x, y, color));
+    Ok(image.write_to_file(filename)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{climb_route, parse_input};
+
+    #[test]
+    fn test_ascii() {
+        let map = parse_input("Sbcdefghijklmn\nEzyxwvutsrqpoo").unwrap();
+        let route = climb_route(&map).unwrap();
+        assert_eq!(ascii(&map, &route), ">>>>>>>>>>>>>v\nE<<<<<<<<<<<<<");
+    }
+
+    #[test]
+    fn test_ascii_example() {
+        let map = parse_input("Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi").unwrap();
+        let route = climb_route(&map).unwrap();
+        let overlay = ascii(&map, &route);
+
+        assert_eq!(overlay.lines().count(), 5);
+        assert_eq!(overlay.chars().filter(|c| "<>^v".contains(*c)).count(), 31);
+        assert!(overlay.contains('E'));
+        assert!(!overlay.contains('S'));
+    }
+}
@@ -0,0 +1,103 @@
+use common::{AocError, AocResult, PartResult, Solution};
+
+use packet::Packet;
+
+mod packet;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<(Packet, Packet)>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, pairs: &Self::Input) -> PartResult<usize> {
+        Ok(part_1(pairs))
+    }
+
+    fn part_2(&self, pairs: &Self::Input) -> PartResult<usize> {
+        Ok(part_2(pairs))
+    }
+}
+
+fn parse_input(input: &str) -> AocResult<Vec<(Packet, Packet)>> {
+    input
+        .split("\n\n")
+        .enumerate()
+        .map(|(index, packet_pair)| {
+            // every pair takes two lines plus the blank separator
+            let first_line = index * 3 + 1;
+            let (left, right) = packet_pair
+                .split_once('\n')
+                .ok_or_else(|| AocError::parse("Packets must come in pairs").at_line(first_line))?;
+            let left = Packet::try_from(left).map_err(|e| e.at_line(first_line))?;
+            let right =
+                Packet::try_from(right.trim_end()).map_err(|e| e.at_line(first_line + 1))?;
+            Ok((left, right))
+        })
+        .collect()
+}
+
+fn part_1(pairs: &[(Packet, Packet)]) -> usize {
+    pairs
+        .iter()
+        .enumerate()
+        .filter_map(|(index, (left, right))| (left < right).then_some(index + 1))
+        .sum()
+}
+
+fn part_2(pairs: &[(Packet, Packet)]) -> usize {
+    let mut packets: Vec<Packet> = pairs
+        .iter()
+        .flat_map(|(left, right)| [left.clone(), right.clone()])
+        .collect();
+    packets.push(Packet::List(vec![Packet::Value(2)]));
+    packets.push(Packet::List(vec![Packet::Value(6)]));
+
+    packets.sort();
+
+    packets
+        .iter()
+        .enumerate()
+        .filter_map(|(index, packet)| {
+            if packet == &Packet::List(vec![Packet::Value(2)])
+                || packet == &Packet::List(vec![Packet::Value(6)])
+            {
+                Some(index + 1)
+            } else {
+                None
+            }
+        })
+        .product::<usize>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_INPUT: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(&parse_input(TEST_INPUT).unwrap()), 13)
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2(&parse_input(TEST_INPUT).unwrap()), 140);
+    }
+
+    #[test]
+    fn test_parse_invalid_input() {
+        assert_eq!(
+            parse_input("[1]\n[2]\n\n[3]\n[4,]]")
+                .unwrap_err()
+                .to_string(),
+            "Parse error at 5:5: Invalid packet"
+        );
+        assert!(parse_input("[1]\n[2]\n\n[3]").is_err());
+    }
+}
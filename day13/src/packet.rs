@@ -1,18 +1,12 @@
-use pest::{iterators::Pair, Parser};
+use common::AocError;
+use pest::{error::LineColLocation, iterators::Pair, Parser};
 use pest_derive::Parser;
 
-#[derive(Debug)]
-pub enum ParseError {
-    InvalidDigit(String),
-    NoPacketFound,
-    InvalidPacket,
-}
-
 #[derive(Parser)]
 #[grammar = "parser.pest"]
 struct PacketParser;
 
-#[derive(Debug, Clone, PartialEq, Eq, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Packet {
     List(Vec<Packet>),
     Value(usize),
@@ -28,26 +22,33 @@ impl Packet {
 }
 
 impl TryFrom<&str> for Packet {
-    type Error = ParseError;
+    type Error = AocError;
 
     fn try_from(line: &str) -> Result<Self, Self::Error> {
-        PacketParser::parse(Rule::list, line)
-            .map_err(|_| ParseError::InvalidPacket)?
+        PacketParser::parse(Rule::packet, line)
+            .map_err(|e| {
+                let column = match e.line_col {
+                    LineColLocation::Pos((_, column)) | LineColLocation::Span((_, column), _) => {
+                        column
+                    }
+                };
+                AocError::parse("Invalid packet").at_column(column)
+            })?
             .next()
-            .ok_or_else(|| ParseError::NoPacketFound)
+            .ok_or_else(|| AocError::parse("No packet found"))
             .and_then(|r| r.try_into())
     }
 }
 
 impl<'a> TryFrom<Pair<'a, Rule>> for Packet {
-    type Error = ParseError;
+    type Error = AocError;
     fn try_from(rule: Pair<Rule>) -> Result<Self, Self::Error> {
         match rule.as_rule() {
             Rule::number => {
                 let value = rule
                     .as_str()
                     .parse::<usize>()
-                    .map_err(|_| ParseError::InvalidDigit(rule.as_str().to_owned()))?;
+                    .map_err(|_| AocError::parse(format!("Invalid digit: {}", rule.as_str())))?;
                 Ok(Packet::Value(value))
             }
             Rule::list => {
@@ -65,8 +66,14 @@ impl<'a> TryFrom<Pair<'a, Rule>> for Packet {
 
 impl PartialOrd<Packet> for Packet {
     fn partial_cmp(&self, other: &Packet) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Packet) -> std::cmp::Ordering {
         match (self, other) {
-            (Packet::Value(a), Packet::Value(b)) => a.partial_cmp(b),
+            (Packet::Value(a), Packet::Value(b)) => a.cmp(b),
             (Packet::List(a), Packet::List(b)) => {
                 let mut a_iter = a.iter();
                 let mut b_iter = b.iter();
@@ -76,18 +83,18 @@ impl PartialOrd<Packet> for Packet {
                     let b_value = b_iter.next();
                     match (a_value, b_value) {
                         (Some(a), Some(b)) => {
-                            let cmp = a.partial_cmp(b);
-                            if cmp != Some(std::cmp::Ordering::Equal) {
+                            let cmp = a.cmp(b);
+                            if cmp != std::cmp::Ordering::Equal {
                                 return cmp;
                             }
                         }
-                        (Some(_), None) => return Some(std::cmp::Ordering::Greater),
-                        (None, Some(_)) => return Some(std::cmp::Ordering::Less),
-                        (None, None) => return Some(std::cmp::Ordering::Equal),
+                        (Some(_), None) => return std::cmp::Ordering::Greater,
+                        (None, Some(_)) => return std::cmp::Ordering::Less,
+                        (None, None) => return std::cmp::Ordering::Equal,
                     }
                 }
             }
-            (a, b) => a.to_list().partial_cmp(&b.to_list()),
+            (a, b) => a.to_list().cmp(&b.to_list()),
         }
     }
 }
@@ -98,7 +105,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_simple_list() -> Result<(), ParseError> {
+    fn parse_simple_list() -> Result<(), AocError> {
         let parsed_packet: Packet = "[1,1,3,1,1]".try_into()?;
         assert_eq!(
             parsed_packet,
@@ -109,7 +116,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_nested_lists() -> Result<(), ParseError> {
+    fn parse_nested_lists() -> Result<(), AocError> {
         let parsed_packet: Packet = "[[1],[2,3,4]]".try_into()?;
         assert_eq!(
             parsed_packet,
@@ -123,7 +130,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_mixed_list() -> Result<(), ParseError> {
+    fn parse_mixed_list() -> Result<(), AocError> {
         let parsed_packet: Packet = "[[4,4],4,4]".try_into()?;
         assert_eq!(
             parsed_packet,
@@ -134,7 +141,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_empty_list() -> Result<(), ParseError> {
+    fn parse_empty_list() -> Result<(), AocError> {
         let parsed_packet: Packet = "[]".try_into()?;
         assert_eq!(parsed_packet, List(vec![]));
 
@@ -142,13 +149,21 @@ mod tests {
     }
 
     #[test]
-    fn parse_nested_empty_list() -> Result<(), ParseError> {
+    fn parse_nested_empty_list() -> Result<(), AocError> {
         let parsed_packet: Packet = "[[[]]]".try_into()?;
         assert_eq!(parsed_packet, List(vec![List(vec![List(vec![])])]));
 
         Ok(())
     }
 
+    #[test]
+    fn parse_invalid_packets() {
+        let error = Packet::try_from("[1,[2,x]]").unwrap_err();
+        assert_eq!(error.to_string(), "Parse error at column 7: Invalid packet");
+        assert!(Packet::try_from("[1]]").is_err());
+        assert!(Packet::try_from("").is_err());
+    }
+
     macro_rules! assert_order {
         ($a:literal $op:tt $b:literal) => {{
             let packet_1: Packet = $a.try_into()?;
@@ -161,42 +176,42 @@ mod tests {
     }
 
     #[test]
-    fn test_ordering_simple_arrays() -> Result<(), ParseError> {
+    fn test_ordering_simple_arrays() -> Result<(), AocError> {
         assert_order!("[1,1,3,1,1]" < "[1,1,5,1,1]")
     }
 
     #[test]
-    fn test_ordering_complex_strucutres() -> Result<(), ParseError> {
+    fn test_ordering_complex_strucutres() -> Result<(), AocError> {
         assert_order!("[[1],[2,3,4]]" < "[[1],4]")
     }
 
     #[test]
-    fn test_ordering_different_length() -> Result<(), ParseError> {
+    fn test_ordering_different_length() -> Result<(), AocError> {
         assert_order!("[9]" > "[[8,7,6]]")
     }
 
     #[test]
-    fn test_ordering_lists_with_lists() -> Result<(), ParseError> {
+    fn test_ordering_lists_with_lists() -> Result<(), AocError> {
         assert_order!("[[4,4],4,4]" < "[[4,4],4,4,4]")
     }
 
     #[test]
-    fn test_ordering_different_length_arrays() -> Result<(), ParseError> {
+    fn test_ordering_different_length_arrays() -> Result<(), AocError> {
         assert_order!("[7,7,7,7]" > "[7,7,7]")
     }
 
     #[test]
-    fn test_empty_vs_something() -> Result<(), ParseError> {
+    fn test_empty_vs_something() -> Result<(), AocError> {
         assert_order!("[]" < "[3]")
     }
 
     #[test]
-    fn test_ordering_differently_nested_lists() -> Result<(), ParseError> {
+    fn test_ordering_differently_nested_lists() -> Result<(), AocError> {
         assert_order!("[[[]]]" > "[[]]")
     }
 
     #[test]
-    fn test_really_complex_structure() -> Result<(), ParseError> {
+    fn test_really_complex_structure() -> Result<(), AocError> {
         assert_order!("[1,[2,[3,[4,[5,6,7]]]],8,9]" > "[1,[2,[3,[4,[5,6,0]]]],8,9]")
     }
 }
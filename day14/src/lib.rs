@@ -0,0 +1,270 @@
+use common::{geometry, AocError, AocResult, BoundingBox, PartResult, Point, Solution, SparseGrid};
+use std::str::FromStr;
+
+#[derive(Debug, Clone)]
+pub struct Cave {
+    map: SparseGrid<Content>,
+}
+
+impl Cave {
+    fn new() -> Self {
+        Self {
+            map: SparseGrid::new(),
+        }
+    }
+
+    fn add_floor(&mut self) -> AocResult<()> {
+        let lowest_point = self
+            .map
+            .bounds()
+            .ok_or_else(|| AocError::invalid_state("The cave has no rocks"))?
+            .max
+            .y;
+        self.add_rock(
+            Point::new(0, lowest_point + 2),
+            Point::new(1001, lowest_point + 2),
+        );
+        Ok(())
+    }
+
+    fn add_rock(&mut self, a: Point<isize>, b: Point<isize>) {
+        self.map
+            .extend(geometry::line(a, b).map(|point| (point, Content::Rock)));
+    }
+
+    fn get(&self, point: &Point<isize>) -> Option<&Content> {
+        self.map.get(point)
+    }
+
+    fn insert_sand(&mut self) -> bool {
+        let mut current_position = Point::new(500, 0);
+        if self.get(&current_position).is_some() {
+            return false;
+        }
+        // anything below the lowest rock falls forever
+        let lowest_point = self.map.bounds().map_or(0, |bounds| bounds.max.y);
+
+        loop {
+            if current_position.y > lowest_point {
+                return false;
+            }
+
+            let new_position = [
+                current_position.below(),
+                current_position.below_left(),
+                current_position.below_right(),
+            ]
+            .into_iter()
+            .find(|p| self.get(p).is_none());
+
+            match new_position {
+                Some(position) => current_position = position,
+                None => break,
+            }
+        }
+
+        self.map.insert(current_position, Content::Sand);
+        true
+    }
+
+    #[allow(dead_code)]
+    fn print_to_file(&self) {
+        use common::image_output::color::Color;
+        use common::image_output::png::Image;
+        // cropped to the sand, the floor reaches far past it
+        let sand = self
+            .map
+            .iter()
+            .filter(|(_, content)| **content == Content::Sand)
+            .map(|(point, _)| *point);
+        let Some(bounds) = BoundingBox::from_points(sand) else {
+            return;
+        };
+        let min = bounds.min;
+
+        let mut image = Image::new(bounds.width() as u32, bounds.height() as u32);
+        for (point, content) in self.map.region(bounds) {
+            let color = match content {
+                Some(Content::Sand) => Color::Yellow,
+                Some(Content::Rock) => Color::Gray,
+                None => Color::Black,
+            };
+            image.set((point.x - min.x) as u32, (point.y - min.y) as u32, color);
+        }
+
+        image.write_to_file("output.png").unwrap();
+    }
+}
+
+impl FromStr for Cave {
+    type Err = AocError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut cave = Cave::new();
+
+        for (index, line) in input.lines().enumerate() {
+            let parts: Vec<Point<isize>> = line
+                .split(" -> ")
+                .map(parse_point)
+                .collect::<AocResult<_>>()
+                .map_err(|e| e.at_line(index + 1))?;
+
+            for points in parts.windows(2) {
+                if points[0].x != points[1].x && points[0].y != points[1].y {
+                    return Err(AocError::parse(format!(
+                        "Rock paths must be straight, found {:?} -> {:?}",
+                        points[0], points[1]
+                    ))
+                    .at_line(index + 1));
+                }
+                cave.add_rock(points[0], points[1]);
+            }
+        }
+
+        if cave.map.is_empty() {
+            return Err(AocError::parse("The cave has no rocks"));
+        }
+
+        Ok(cave)
+    }
+}
+
+fn parse_point(point: &str) -> AocResult<Point<isize>> {
+    let (x, y) = point
+        .split_once(',')
+        .ok_or_else(|| AocError::parse(format!("Invalid point '{}'", point)))?;
+    Ok(Point::new(x.parse()?, y.parse()?))
+}
+
+#[derive(Hash, PartialEq, Eq, Debug, Clone)]
+enum Content {
+    Sand,
+    Rock,
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Cave;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        input.parse()
+    }
+
+    fn part_1(&self, cave: &Self::Input) -> PartResult<usize> {
+        Ok(part_1(cave))
+    }
+
+    fn part_2(&self, cave: &Self::Input) -> PartResult<usize> {
+        part_2(cave)
+    }
+}
+
+fn common_cave_part(cave: &mut Cave) -> usize {
+    let mut count = 0;
+    while cave.insert_sand() {
+        count += 1;
+    }
+
+    count
+}
+
+fn part_1(cave: &Cave) -> usize {
+    let mut cave = cave.clone();
+    common_cave_part(&mut cave)
+}
+
+fn part_2(cave: &Cave) -> AocResult<usize> {
+    let mut cave = cave.clone();
+    cave.add_floor()?;
+
+    let result = common_cave_part(&mut cave);
+
+    // cave.print_to_file();
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9";
+
+    fn test_cave() -> Cave {
+        TEST_INPUT.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_invalid_input() {
+        assert_eq!(
+            "498,4 -> 498,6\n503,4 -> 502;4"
+                .parse::<Cave>()
+                .unwrap_err()
+                .to_string(),
+            "Parse error at line 2: Invalid point '502;4'"
+        );
+        assert!("498,4 -> 497,6".parse::<Cave>().is_err());
+        assert!("".parse::<Cave>().is_err());
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let cave = test_cave();
+
+        assert_eq!(cave.get(&Point::new(498, 4)), Some(&Content::Rock));
+        assert_eq!(cave.get(&Point::new(498, 5)), Some(&Content::Rock));
+        assert_eq!(cave.get(&Point::new(498, 6)), Some(&Content::Rock));
+        assert_eq!(cave.get(&Point::new(497, 6)), Some(&Content::Rock));
+        assert_eq!(cave.get(&Point::new(496, 6)), Some(&Content::Rock));
+
+        assert_eq!(cave.get(&Point::new(503, 4)), Some(&Content::Rock));
+        assert_eq!(cave.get(&Point::new(502, 4)), Some(&Content::Rock));
+        assert_eq!(cave.get(&Point::new(502, 8)), Some(&Content::Rock));
+        assert_eq!(cave.get(&Point::new(502, 9)), Some(&Content::Rock));
+        assert_eq!(cave.get(&Point::new(501, 9)), Some(&Content::Rock));
+        assert_eq!(cave.get(&Point::new(495, 9)), Some(&Content::Rock));
+        assert_eq!(cave.get(&Point::new(494, 9)), Some(&Content::Rock));
+
+        assert_eq!(cave.map.len(), 20)
+    }
+
+    #[test]
+    fn test_insert_sand() {
+        let mut cave = test_cave();
+
+        assert!(cave.insert_sand());
+    }
+
+    #[test]
+    fn test_insert_more_sand() {
+        let mut cave = test_cave();
+
+        for _ in 0..24 {
+            assert!(cave.insert_sand());
+        }
+        assert!(!cave.insert_sand())
+    }
+
+    #[test]
+    fn test_add_floor() {
+        let mut cave = test_cave();
+        cave.add_floor().unwrap();
+
+        assert_eq!(cave.get(&Point::new(0, 11)), Some(&Content::Rock));
+        assert_eq!(cave.get(&Point::new(500, 11)), Some(&Content::Rock));
+        assert_eq!(cave.get(&Point::new(1001, 11)), Some(&Content::Rock));
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(&test_cave()), 24)
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2(&test_cave()).unwrap(), 93)
+    }
+}
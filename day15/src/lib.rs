@@ -0,0 +1,366 @@
+use std::{collections::HashSet, ops::RangeInclusive, str::FromStr};
+
+use common::{geometry, Alternative, AocError, AocResult, Part, PartResult, RangeSet, Solution};
+
+type Point = common::Point<isize>;
+
+pub struct Day15 {
+    pub row: isize,
+    pub search_area: RangeInclusive<isize>,
+    pub search: BeaconSearch,
+}
+
+impl Default for Day15 {
+    fn default() -> Self {
+        Self {
+            row: 2000000,
+            search_area: RangeInclusive::new(0, 4000000),
+            search: BeaconSearch::Rotated,
+        }
+    }
+}
+
+// How part 2 looks for the distress beacon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BeaconSearch {
+    // checks every point just outside of every sensor's range
+    Perimeter,
+    // merges the sensors' ranges on each row until one has a gap
+    RowIntervals,
+    // only checks where the borders of two sensors' ranges cross
+    Rotated,
+}
+
+impl BeaconSearch {
+    pub const ALL: [BeaconSearch; 3] = [
+        BeaconSearch::Perimeter,
+        BeaconSearch::RowIntervals,
+        BeaconSearch::Rotated,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            BeaconSearch::Perimeter => "perimeter",
+            BeaconSearch::RowIntervals => "row_intervals",
+            BeaconSearch::Rotated => "rotated",
+        }
+    }
+}
+
+impl FromStr for BeaconSearch {
+    type Err = AocError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|search| search.name() == name)
+            .ok_or_else(|| {
+                let names = Self::ALL.map(BeaconSearch::name);
+                AocError::parse(format!(
+                    "Unknown beacon search '{}', expected one of {}",
+                    name,
+                    names.join(", ")
+                ))
+            })
+    }
+}
+
+impl Solution for Day15 {
+    type Input = Vec<Sensor>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, sensors: &Self::Input) -> PartResult<usize> {
+        Ok(part_1(sensors, self.row))
+    }
+
+    fn part_2(&self, sensors: &Self::Input) -> PartResult<usize> {
+        self.tuning_frequency(sensors, self.search)
+    }
+
+    fn alternatives(&self) -> Vec<Alternative<Self>> {
+        let alternatives = vec![
+            Alternative::new(
+                "perimeter",
+                Part::Two,
+                |day: &Self, sensors: &Vec<Sensor>| {
+                    day.tuning_frequency(sensors, BeaconSearch::Perimeter)
+                        .map(|frequency| frequency.to_string())
+                },
+            ),
+            Alternative::new(
+                "row_intervals",
+                Part::Two,
+                |day: &Self, sensors: &Vec<Sensor>| {
+                    day.tuning_frequency(sensors, BeaconSearch::RowIntervals)
+                        .map(|frequency| frequency.to_string())
+                },
+            ),
+            Alternative::new("rotated", Part::Two, |day: &Self, sensors: &Vec<Sensor>| {
+                day.tuning_frequency(sensors, BeaconSearch::Rotated)
+                    .map(|frequency| frequency.to_string())
+            }),
+        ];
+
+        alternatives
+            .into_iter()
+            .filter(|alternative| alternative.name != self.search.name())
+            .collect()
+    }
+}
+
+impl Day15 {
+    pub fn tuning_frequency(&self, sensors: &[Sensor], search: BeaconSearch) -> AocResult<usize> {
+        let area = self.search_area.clone();
+        let beacon = match search {
+            BeaconSearch::Perimeter => search_perimeter(sensors, area),
+            BeaconSearch::RowIntervals => search_rows(sensors, area),
+            BeaconSearch::Rotated => search_rotated(sensors, area),
+        }?;
+
+        Ok(beacon.x as usize * 4000000 + beacon.y as usize)
+    }
+}
+
+#[derive(Debug)]
+pub struct Sensor {
+    center: Point,
+    beacon: Point,
+    radius: usize,
+}
+
+impl Sensor {
+    fn just_outside_of_border(&self) -> impl Iterator<Item = Point> {
+        geometry::manhattan_border(self.center, self.radius + 1)
+    }
+
+    fn can_see(&self, point: &Point) -> bool {
+        self.center.cab_distance(point) <= self.radius
+    }
+
+    // the x coordinates this sensor can see on row `y`
+    fn row_coverage(&self, y: isize) -> Option<RangeInclusive<isize>> {
+        let reach = self.radius.checked_sub(self.center.y.abs_diff(y))? as isize;
+        Some(self.center.x - reach..=self.center.x + reach)
+    }
+}
+
+impl FromStr for Sensor {
+    type Err = AocError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let captures = regex::Regex::new(
+            "^Sensor at x=([^,]+), y=([^:]+): closest beacon is at x=([^,]+), y=([^ ]+)$",
+        )
+        .expect("The sensor regex is valid")
+        .captures(input)
+        .ok_or_else(|| AocError::parse(format!("Invalid sensor '{}'", input)))?;
+
+        let coordinate = |index: usize| {
+            let capture = captures.get(index).expect("The regex has four groups");
+            capture
+                .as_str()
+                .parse::<isize>()
+                .map_err(|e| AocError::from(e).at_column(capture.start() + 1))
+        };
+
+        let center = Point::new(coordinate(1)?, coordinate(2)?);
+        let beacon = Point::new(coordinate(3)?, coordinate(4)?);
+        let radius = center.cab_distance(&beacon);
+        Ok(Self {
+            center,
+            radius,
+            beacon,
+        })
+    }
+}
+
+fn parse_input(input: &str) -> AocResult<Vec<Sensor>> {
+    common::error::parse_lines(input)
+}
+
+fn part_1(sensors: &[Sensor], row: isize) -> usize {
+    let covered = sensors
+        .iter()
+        .filter_map(|s| s.row_coverage(row))
+        .collect::<RangeSet<_>>();
+
+    let beacons_on_line = sensors
+        .iter()
+        .filter_map(|s| (s.beacon.y == row).then_some(s.beacon))
+        .collect::<HashSet<_>>()
+        .len();
+
+    covered.len() - beacons_on_line
+}
+
+fn not_found() -> AocError {
+    AocError::no_solution("Every point in the search area is covered")
+}
+
+fn search_perimeter(sensors: &[Sensor], area: RangeInclusive<isize>) -> AocResult<Point> {
+    sensors
+        .iter()
+        .flat_map(|s| s.just_outside_of_border())
+        .filter(|p| area.contains(&p.x) && area.contains(&p.y))
+        .find(|point| sensors.iter().all(|s| !s.can_see(point)))
+        .ok_or_else(not_found)
+}
+
+fn search_rows(sensors: &[Sensor], area: RangeInclusive<isize>) -> AocResult<Point> {
+    area.clone()
+        .find_map(|y| {
+            let covered = sensors
+                .iter()
+                .filter_map(|s| s.row_coverage(y))
+                .collect::<RangeSet<_>>();
+            let gap = covered.gaps(area.clone()).ranges().next().cloned()?;
+            Some(Point::new(*gap.start(), y))
+        })
+        .ok_or_else(not_found)
+}
+
+// With u = x + y and v = x - y every sensor's range becomes a square. A lone
+// uncovered point is boxed in by the squares around it, so its u and v are
+// each one past some square's edge. A point on the border of the search area
+// might not be, so the row scan takes over when nothing is found.
+fn search_rotated(sensors: &[Sensor], area: RangeInclusive<isize>) -> AocResult<Point> {
+    let mut us = HashSet::new();
+    let mut vs = HashSet::new();
+    for sensor in sensors {
+        let (u, v) = (
+            sensor.center.x + sensor.center.y,
+            sensor.center.x - sensor.center.y,
+        );
+        let reach = sensor.radius as isize + 1;
+        us.extend([u - reach, u + reach]);
+        vs.extend([v - reach, v + reach]);
+    }
+
+    us.iter()
+        .flat_map(|u| vs.iter().map(move |v| (u, v)))
+        .filter(|(u, v)| (*u - *v).rem_euclid(2) == 0)
+        .map(|(u, v)| Point::new((u + v) / 2, (u - v) / 2))
+        .find(|point| {
+            area.contains(&point.x)
+                && area.contains(&point.y)
+                && sensors.iter().all(|s| !s.can_see(point))
+        })
+        .map_or_else(|| search_rows(sensors, area), Ok)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = r#"Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3"#;
+
+    #[test]
+    fn test_parse_sensor() {
+        let sensor: Sensor = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15"
+            .parse()
+            .unwrap();
+        assert_eq!(sensor.center, Point::new(2, 18));
+        assert_eq!(sensor.radius, 7);
+    }
+
+    #[test]
+    fn test_parse_invalid_sensor() {
+        let input = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15\n\
+                     Sensor at x=9, y=1b: closest beacon is at x=10, y=16";
+        assert_eq!(
+            parse_input(input).unwrap_err().to_string(),
+            "Parse error at 2:18: invalid digit found in string"
+        );
+        assert!(parse_input("Sensor at x=9, y=16").is_err());
+    }
+
+    #[test]
+    fn test_just_outside_of_border() {
+        let sensor: Sensor = Sensor {
+            center: Point::new(0, 0),
+            beacon: Point::new(1, 3),
+            radius: 2,
+        };
+        assert_eq!(sensor.just_outside_of_border().count(), 12);
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(&parse_input(TEST_INPUT).unwrap(), 10), 26);
+    }
+
+    fn test_day() -> Day15 {
+        Day15 {
+            row: 10,
+            search_area: 0..=20,
+            ..Day15::default()
+        }
+    }
+
+    #[test]
+    fn test_part_2() {
+        let sensors = parse_input(TEST_INPUT).unwrap();
+        for search in BeaconSearch::ALL {
+            assert_eq!(
+                test_day().tuning_frequency(&sensors, search).unwrap(),
+                56000011,
+                "{:?}",
+                search
+            );
+        }
+    }
+
+    #[test]
+    fn test_beacon_on_the_border() {
+        // the only uncovered point is (0, 0), in a corner of the search area
+        let sensors = parse_input("Sensor at x=2, y=2: closest beacon is at x=5, y=2").unwrap();
+        let day = Day15 {
+            search_area: 0..=3,
+            ..test_day()
+        };
+        for search in BeaconSearch::ALL {
+            assert_eq!(
+                day.tuning_frequency(&sensors, search).unwrap(),
+                0,
+                "{:?}",
+                search
+            );
+        }
+    }
+
+    #[test]
+    fn test_alternatives() {
+        let names = test_day()
+            .alternatives()
+            .iter()
+            .map(|a| a.name)
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["perimeter", "row_intervals"]);
+        assert_eq!(
+            "row_intervals".parse::<BeaconSearch>().unwrap(),
+            BeaconSearch::RowIntervals
+        );
+        assert_eq!(
+            "sweep".parse::<BeaconSearch>().unwrap_err().to_string(),
+            "Parse error: Unknown beacon search 'sweep', expected one of perimeter, row_intervals, rotated"
+        );
+    }
+}
@@ -1,32 +1,42 @@
+use common::{AocError, AocResult, Graph};
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::schedule::Schedule;
+use crate::solver::{self, ValveSearch};
 
 lazy_static! {
     static ref REGEX: Regex = regex::Regex::new(
-        r#"Valve ([A-Z][A-Z]) has flow rate=(\d+); tunnels? leads? to valves? ((?:[A-Z][A-Z](?:, )?)+)"#,
+        r#"^Valve ([A-Z][A-Z]) has flow rate=(\d+); tunnels? leads? to valves? ((?:[A-Z][A-Z](?:, )?)+)$"#,
     )
     .unwrap();
 }
 
 type DistanceMatrix = Vec<Vec<usize>>;
 
+const START: &str = "AA";
+
 #[derive(Clone, Debug)]
 struct Valve {
     name: usize,
+    label: String,
     rate: usize,
     neighbors: Vec<usize>,
 }
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct CaveNetwork {
     valves: Vec<Valve>,
+    start: usize,
 }
 
 impl Valve {
-    fn new(name: usize, rate: usize, neighbors: Vec<usize>) -> Self {
+    fn new(name: usize, label: &str, rate: usize, neighbors: Vec<usize>) -> Self {
         Valve {
             name,
+            label: label.to_string(),
             rate,
             neighbors,
         }
@@ -37,41 +47,51 @@ impl Valve {
     }
 }
 
-impl From<&str> for CaveNetwork {
-    fn from(input: &str) -> Self {
-        let valve_map =
-            input
-                .lines()
-                .enumerate()
-                .fold(HashMap::new(), |mut map, (index, valve)| {
-                    let name = REGEX
-                        .captures(valve)
-                        .unwrap()
-                        .get(1)
-                        .unwrap()
-                        .as_str()
-                        .to_owned();
-                    map.insert(name, index);
-
-                    map
-                });
-
-        let valves = input
+impl FromStr for CaveNetwork {
+    type Err = AocError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let captures = input
             .lines()
-            .map(|valve| {
-                let captures = REGEX.captures(valve).unwrap();
-                let name = captures[1].to_string();
-                let name = valve_map.get(&name).cloned().unwrap();
-                let rate = captures[2].parse().unwrap();
+            .enumerate()
+            .map(|(index, valve)| {
+                REGEX.captures(valve).ok_or_else(|| {
+                    AocError::parse(format!("Invalid valve '{}'", valve)).at_line(index + 1)
+                })
+            })
+            .collect::<AocResult<Vec<_>>>()?;
+
+        let valve_map: HashMap<&str, usize> = captures
+            .iter()
+            .enumerate()
+            .map(|(index, captures)| (captures.get(1).unwrap().as_str(), index))
+            .collect();
+
+        let valves = captures
+            .iter()
+            .enumerate()
+            .map(|(index, captures)| {
+                let rate = captures[2]
+                    .parse()
+                    .map_err(|e| AocError::from(e).at_line(index + 1))?;
                 let neighbors = captures[3]
                     .split(", ")
-                    .map(|neigh| valve_map.get(neigh).cloned().unwrap())
-                    .collect();
-                Valve::new(name, rate, neighbors)
+                    .map(|neigh| {
+                        valve_map.get(neigh).cloned().ok_or_else(|| {
+                            AocError::parse(format!("Tunnel to unknown valve {}", neigh))
+                                .at_line(index + 1)
+                        })
+                    })
+                    .collect::<AocResult<_>>()?;
+                Ok(Valve::new(index, &captures[1], rate, neighbors))
             })
-            .collect();
+            .collect::<AocResult<_>>()?;
 
-        CaveNetwork { valves }
+        let start = *valve_map
+            .get(START)
+            .ok_or_else(|| AocError::parse(format!("No valve {} to start from", START)))?;
+
+        Ok(CaveNetwork { valves, start })
     }
 }
 
@@ -99,16 +119,51 @@ impl CaveNetwork {
         dist
     }
 
-    pub fn most_exhausted_gas(&self) -> usize {
-        let distance_matrix = self.calculate_distance_matrix();
-
-        let worthy_valves: Vec<usize> = self
-            .valves
+    fn worthy_valves(&self) -> Vec<usize> {
+        self.valves
             .iter()
-            // .map(|v| v.name)
             .filter_map(|v| v.worthy().then_some(v.name))
+            .collect()
+    }
+
+    // the graph reduced to the worthy valves and the start
+    fn search(&self, distance_matrix: &DistanceMatrix) -> ValveSearch {
+        let worthy_valves = self.worthy_valves();
+        let positions = worthy_valves
+            .iter()
+            .copied()
+            .chain([self.start])
+            .collect::<Vec<_>>();
+        let distances = positions
+            .iter()
+            .map(|from| {
+                positions
+                    .iter()
+                    .map(|to| distance_matrix[*from][*to])
+                    .collect()
+            })
             .collect();
-        self.run_sequence(&distance_matrix, worthy_valves, 0, 0, 0)
+        let rates = worthy_valves.iter().map(|v| self.valves[*v].rate).collect();
+
+        ValveSearch::new(rates, distances)
+    }
+
+    /// The most pressure that `actors` walkers, all starting at the same time
+    /// from AA, can release together in `minutes`
+    pub fn most_released_pressure(&self, minutes: usize, actors: usize) -> usize {
+        let search = self.search(&self.calculate_distance_matrix());
+        match actors {
+            0 => 0,
+            1 => search.best(minutes),
+            _ => solver::combine(search.best_per_subset(minutes), actors),
+        }
+    }
+
+    /// The most pressure a single walker can release in 30 minutes, trying
+    /// every order of the worthy valves without pruning or memoisation
+    pub fn most_released_pressure_permutations(&self) -> usize {
+        let distance_matrix = self.calculate_distance_matrix();
+        self.run_sequence(&distance_matrix, self.worthy_valves(), self.start, 0, 0)
     }
 
     fn run_sequence(
@@ -124,11 +179,9 @@ impl CaveNetwork {
         };
 
         let current_valve = &self.valves[position];
-        if sequence.is_empty() {
-            if current_valve.worthy() && time_passed < 30 {
-                let exhausted_by_me = current_valve.rate * (29 - time_passed);
-                return exhausted + exhausted_by_me;
-            }
+        if sequence.is_empty() && current_valve.worthy() && time_passed < 30 {
+            let exhausted_by_me = current_valve.rate * (29 - time_passed);
+            return exhausted + exhausted_by_me;
         }
 
         sequence
@@ -165,27 +218,104 @@ impl CaveNetwork {
                 }
             })
             .max()
-            .unwrap()
+            .unwrap_or(exhausted)
+    }
+
+    /// What each actor should do every minute to release the most pressure,
+    /// walking along shortest paths between the valves it opens
+    pub fn schedule(&self, minutes: usize, actors: usize) -> Schedule {
+        let distance_matrix = self.calculate_distance_matrix();
+        let search = self.search(&distance_matrix);
+        let worthy_valves = self.worthy_valves();
+        let all = (1 << worthy_valves.len()) - 1;
+        let shares = match actors {
+            0 | 1 => vec![all; actors],
+            _ => solver::split(search.best_per_subset(minutes), actors).1,
+        };
+
+        let mut schedule = Schedule::new(minutes);
+        for (actor, share) in shares.into_iter().enumerate() {
+            let mut position = self.start;
+            let mut minute = 1;
+            for valve in search.plan(minutes, share).valves {
+                let target = worthy_valves[valve];
+                while position != target {
+                    position = self.next_hop(&distance_matrix, position, target);
+                    schedule.move_to(minute, actor, &self.valves[position].label);
+                    minute += 1;
+                }
+                let valve = &self.valves[target];
+                schedule.open(minute, actor, &valve.label, valve.rate);
+                minute += 1;
+            }
+        }
+
+        schedule
+    }
+
+    /// The tunnels as a GraphViz graph, with the valves worth opening filled in
+    pub fn to_dot(&self) -> String {
+        let mut graph = Graph::undirected();
+        for valve in &self.valves {
+            graph.add_node(valve);
+        }
+        for valve in &self.valves {
+            for neighbor in &valve.neighbors {
+                graph.add_edge(valve.name, *neighbor);
+            }
+        }
+
+        graph.to_dot("valves", |valve| {
+            let mut attributes = vec![("label", format!("{} ({})", valve.label, valve.rate))];
+            if valve.worthy() {
+                attributes.push(("style", "filled".to_string()));
+                attributes.push(("fillcolor", "gold".to_string()));
+            }
+            if valve.name == self.start {
+                attributes.push(("shape", "doublecircle".to_string()));
+            }
+            attributes
+        })
+    }
+
+    // the first tunnel to take on a shortest path from `from` to `to`
+    fn next_hop(&self, distance_matrix: &DistanceMatrix, from: usize, to: usize) -> usize {
+        *self.valves[from]
+            .neighbors
+            .iter()
+            .find(|neighbor| distance_matrix[**neighbor][to] + 1 == distance_matrix[from][to])
+            .expect("A shortest path goes through one of the neighbors")
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::schedule::Action;
 
     const TEST_INPUT: &str = include_str!("../test_input.txt");
 
     #[test]
     fn test_input_parsing() {
-        let cave_network = CaveNetwork::from(TEST_INPUT);
+        let cave_network = TEST_INPUT.parse::<CaveNetwork>().unwrap();
 
         assert_eq!(cave_network.valves.len(), 10);
         assert_eq!(cave_network.valves[0].neighbors, vec![3, 8, 1]);
     }
 
+    #[test]
+    fn test_invalid_input() {
+        let input = TEST_INPUT.replacen("valves DD, II, BB", "valves DD, XX, BB", 1);
+        assert_eq!(
+            input.parse::<CaveNetwork>().unwrap_err().to_string(),
+            "Parse error at line 1: Tunnel to unknown valve XX"
+        );
+        assert!("Valve AA has flow rate=0".parse::<CaveNetwork>().is_err());
+    }
+
     #[test]
     fn test_calculate_distance_matrix() {
-        let cave_network = CaveNetwork::from(TEST_INPUT);
+        let cave_network = TEST_INPUT.parse::<CaveNetwork>().unwrap();
 
         let distance_matrix = cave_network.calculate_distance_matrix();
         // Distance between a valve and itself is always 0
@@ -198,19 +328,104 @@ mod tests {
     }
 
     #[test]
-    fn test_most_exhausted_gas() {
-        let cave_network = CaveNetwork::from(TEST_INPUT);
-        assert_eq!(cave_network.most_exhausted_gas(), 1651)
+    fn test_most_released_pressure() {
+        let cave_network = TEST_INPUT.parse::<CaveNetwork>().unwrap();
+        assert_eq!(cave_network.most_released_pressure(30, 1), 1651);
+        assert_eq!(cave_network.most_released_pressure(26, 2), 1707);
+        assert_eq!(cave_network.most_released_pressure(30, 0), 0);
+        assert_eq!(cave_network.most_released_pressure(1, 2), 0);
     }
 
     #[test]
-    fn test_run_sequence() {
-        let cave_network = CaveNetwork::from(TEST_INPUT);
-        let distance_matrix = cave_network.calculate_distance_matrix();
+    fn test_most_released_pressure_permutations() {
+        let cave_network = TEST_INPUT.parse::<CaveNetwork>().unwrap();
+        assert_eq!(cave_network.most_released_pressure_permutations(), 1651);
+    }
+
+    #[test]
+    fn test_search() {
+        let cave_network = TEST_INPUT.parse::<CaveNetwork>().unwrap();
+        let search = cave_network.search(&cave_network.calculate_distance_matrix());
+        let best = search.best_per_subset(30);
+
+        assert_eq!(best.len(), 1 << 6);
+        assert_eq!(best[0], 0);
+        assert_eq!(best.iter().max(), Some(&1651));
+        // only BB, opened at minute 2
+        assert_eq!(best[0b000001], 13 * 28);
+    }
+
+    #[test]
+    fn test_missing_start() {
+        let input = "Valve BB has flow rate=13; tunnel leads to valve CC\n\
+                     Valve CC has flow rate=2; tunnel leads to valve BB";
+        assert_eq!(
+            input.parse::<CaveNetwork>().unwrap_err().to_string(),
+            "Parse error: No valve AA to start from"
+        );
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let cave_network = TEST_INPUT.parse::<CaveNetwork>().unwrap();
+        let dot = cave_network.to_dot();
 
+        assert!(dot.starts_with("graph \"valves\" {\n"));
+        assert!(dot.contains("    n0 [label=\"AA (0)\", shape=\"doublecircle\"];\n"));
+        assert!(dot.contains("    n1 [label=\"BB (13)\", style=\"filled\", fillcolor=\"gold\"];\n"));
+        assert!(dot.contains("    n0 -- n3;\n"));
+        // every tunnel is listed from both ends, but drawn once
+        assert_eq!(dot.matches(" -- ").count(), 10);
+    }
+
+    #[test]
+    fn test_schedule() {
+        let cave_network = TEST_INPUT.parse::<CaveNetwork>().unwrap();
+        let schedule = cave_network.schedule(30, 1);
+        assert_eq!(schedule.total_released(), 1651);
+
+        let opened = schedule
+            .steps()
+            .iter()
+            .filter_map(|step| match &step.action {
+                Action::Open(valve) => Some((step.minute, valve.as_str())),
+                Action::Move(_) => None,
+            })
+            .collect::<Vec<_>>();
         assert_eq!(
-            cave_network.run_sequence(&distance_matrix, vec![1, 2, 3, 4, 7, 9], 0, 0, 0),
-            1651
+            opened,
+            vec![
+                (2, "DD"),
+                (5, "BB"),
+                (9, "JJ"),
+                (17, "HH"),
+                (21, "EE"),
+                (24, "CC")
+            ]
         );
+        assert!(schedule.to_string().starts_with(
+            "== Minute 1 ==\n\
+             No valves are open.\n\
+             You move to valve DD.\n\
+             \n\
+             == Minute 2 ==\n\
+             No valves are open.\n\
+             You open valve DD.\n\
+             \n\
+             == Minute 3 ==\n\
+             Valve DD is open, releasing 20 pressure.\n\
+             You move to valve CC.\n"
+        ));
+    }
+
+    #[test]
+    fn test_schedule_with_elephant() {
+        let cave_network = TEST_INPUT.parse::<CaveNetwork>().unwrap();
+        let schedule = cave_network.schedule(26, 2);
+        assert_eq!(schedule.total_released(), 1707);
+        assert_eq!(schedule.released_per_minute()[25], 81);
+        assert!(schedule.steps().iter().any(|step| step.actor == 1));
+
+        assert_eq!(cave_network.schedule(30, 0).total_released(), 0);
     }
 }
@@ -0,0 +1,58 @@
+use common::{Alternative, AocResult, Part, PartResult, Solution};
+
+mod cavemap;
+pub mod schedule;
+mod solver;
+
+pub use cavemap::CaveNetwork;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = CaveNetwork;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        input.parse()
+    }
+
+    fn part_1(&self, cave_network: &Self::Input) -> PartResult<usize> {
+        Ok(cave_network.most_released_pressure(30, 1))
+    }
+
+    fn part_2(&self, cave_network: &Self::Input) -> PartResult<usize> {
+        Ok(cave_network.most_released_pressure(26, 2))
+    }
+
+    fn alternatives(&self) -> Vec<Alternative<Self>> {
+        vec![Alternative::new(
+            "permutations",
+            Part::One,
+            |_, cave_network: &CaveNetwork| {
+                Ok(cave_network
+                    .most_released_pressure_permutations()
+                    .to_string())
+            },
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn test_part_1() {
+        let cave_network = Day16.parse(TEST_INPUT).unwrap();
+        assert_eq!(Day16.part_1(&cave_network).unwrap(), 1651);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let cave_network = Day16.parse(TEST_INPUT).unwrap();
+        assert_eq!(Day16.part_2(&cave_network).unwrap(), 1707);
+    }
+}
@@ -1,23 +1,23 @@
-mod cavemap;
-
-const INPUT: &str = include_str!("../input.txt");
+use common::Solution;
+use day16::Day16;
 
 fn main() {
-    println!("{}", part_1(INPUT));
-}
-
-fn part_1(input: &str) -> usize {
-    cavemap::CaveNetwork::from(input).most_exhausted_gas()
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
+    let puzzle_input = common::input::for_day(16).unwrap();
+    let cave_network = Day16.parse(&puzzle_input).unwrap();
+    println!("Part 1: {}", Day16.part_1(&cave_network).unwrap());
+    println!("Part 2: {}", Day16.part_2(&cave_network).unwrap());
 
-    const TEST_INPUT: &str = include_str!("../test_input.txt");
+    if std::env::args().any(|arg| arg == "--schedule") {
+        let schedules = [
+            ("part_1", cave_network.schedule(30, 1)),
+            ("part_2", cave_network.schedule(26, 2)),
+        ];
+        for (name, schedule) in schedules {
+            println!("\n{}\n{}", name, schedule);
+        }
+    }
 
-    #[test]
-    fn test_part_1() {
-        assert_eq!(part_1(TEST_INPUT), 1651);
+    if std::env::args().any(|arg| arg == "--dot") {
+        std::fs::write("day16.dot", cave_network.to_dot()).unwrap();
     }
 }
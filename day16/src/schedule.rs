@@ -0,0 +1,192 @@
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    Move(String),
+    Open(String),
+}
+
+// What one actor does during one minute, counting minutes from 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub minute: usize,
+    pub actor: usize,
+    pub action: Action,
+}
+
+// Every step of every actor, sorted by minute and then by actor
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schedule {
+    steps: Vec<Step>,
+    // the pressure released during each minute
+    released: Vec<usize>,
+}
+
+impl Schedule {
+    pub fn new(minutes: usize) -> Self {
+        Self {
+            steps: vec![],
+            released: vec![0; minutes],
+        }
+    }
+
+    pub fn steps(&self) -> &[Step] {
+        &self.steps
+    }
+
+    pub fn released_per_minute(&self) -> &[usize] {
+        &self.released
+    }
+
+    pub fn total_released(&self) -> usize {
+        self.released.iter().sum()
+    }
+
+    pub fn move_to(&mut self, minute: usize, actor: usize, valve: &str) {
+        self.add(minute, actor, Action::Move(valve.to_string()));
+    }
+
+    // An opened valve starts releasing pressure the minute after
+    pub fn open(&mut self, minute: usize, actor: usize, valve: &str, rate: usize) {
+        for released in self.released.iter_mut().skip(minute) {
+            *released += rate;
+        }
+        self.add(minute, actor, Action::Open(valve.to_string()));
+    }
+
+    fn add(&mut self, minute: usize, actor: usize, action: Action) {
+        let index = self
+            .steps
+            .partition_point(|step| (step.minute, step.actor) <= (minute, actor));
+        self.steps.insert(
+            index,
+            Step {
+                minute,
+                actor,
+                action,
+            },
+        );
+    }
+}
+
+fn actor_name(actor: usize) -> String {
+    match actor {
+        0 => "You".to_string(),
+        1 => "The elephant".to_string(),
+        _ => format!("Elephant {}", actor),
+    }
+}
+
+fn list(names: &[&str]) -> String {
+    match names {
+        [] => String::new(),
+        [only] => only.to_string(),
+        [first, second] => format!("{} and {}", first, second),
+        [rest @ .., last] => format!("{}, and {}", rest.join(", "), last),
+    }
+}
+
+// Same layout as the walkthrough in the puzzle
+impl Display for Schedule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut open: Vec<&str> = vec![];
+        let mut steps = self.steps.iter().peekable();
+
+        for (index, released) in self.released.iter().enumerate() {
+            let minute = index + 1;
+            if index > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "== Minute {} ==", minute)?;
+            match open.len() {
+                0 => writeln!(f, "No valves are open.")?,
+                1 => writeln!(
+                    f,
+                    "Valve {} is open, releasing {} pressure.",
+                    open[0], released
+                )?,
+                _ => writeln!(
+                    f,
+                    "Valves {} are open, releasing {} pressure.",
+                    list(&open),
+                    released
+                )?,
+            }
+
+            while let Some(step) = steps.next_if(|step| step.minute == minute) {
+                let actor = actor_name(step.actor);
+                let verb = |you: &'static str, other: &'static str| match step.actor {
+                    0 => you,
+                    _ => other,
+                };
+                match &step.action {
+                    Action::Move(valve) => {
+                        writeln!(f, "{} {} to valve {}.", actor, verb("move", "moves"), valve)?
+                    }
+                    Action::Open(valve) => {
+                        writeln!(f, "{} {} valve {}.", actor, verb("open", "opens"), valve)?;
+                        open.push(valve);
+                    }
+                }
+            }
+            open.sort();
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_released() {
+        let mut schedule = Schedule::new(5);
+        schedule.move_to(1, 0, "BB");
+        schedule.open(2, 0, "BB", 13);
+        schedule.open(1, 1, "AA", 2);
+
+        assert_eq!(schedule.released_per_minute(), &[0, 2, 15, 15, 15]);
+        assert_eq!(schedule.total_released(), 47);
+        assert_eq!(
+            schedule
+                .steps()
+                .iter()
+                .map(|step| (step.minute, step.actor))
+                .collect::<Vec<_>>(),
+            vec![(1, 0), (1, 1), (2, 0)]
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let mut schedule = Schedule::new(3);
+        schedule.move_to(1, 0, "DD");
+        schedule.open(1, 1, "JJ", 21);
+        schedule.open(2, 0, "DD", 20);
+        schedule.open(2, 2, "BB", 13);
+
+        assert_eq!(
+            schedule.to_string(),
+            "== Minute 1 ==\n\
+             No valves are open.\n\
+             You move to valve DD.\n\
+             The elephant opens valve JJ.\n\
+             \n\
+             == Minute 2 ==\n\
+             Valve JJ is open, releasing 21 pressure.\n\
+             You open valve DD.\n\
+             Elephant 2 opens valve BB.\n\
+             \n\
+             == Minute 3 ==\n\
+             Valves BB, DD, and JJ are open, releasing 54 pressure.\n"
+        );
+    }
+
+    #[test]
+    fn test_list() {
+        assert_eq!(list(&["AA", "BB"]), "AA and BB");
+        assert_eq!(list(&["AA"]), "AA");
+    }
+}
@@ -0,0 +1,338 @@
+use std::collections::HashMap;
+
+// (position, opened valves, minutes remaining)
+type State = (usize, usize, usize);
+
+// The valves worth opening, numbered from 0, with the start as one extra
+// position after them. Sets of opened valves are bitmasks over those numbers.
+pub struct ValveSearch {
+    rates: Vec<usize>,
+    distances: Vec<Vec<usize>>,
+    by_rate: Vec<usize>,
+}
+
+// The valves to open, in order, and the pressure they release
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Plan {
+    pub released: usize,
+    pub valves: Vec<usize>,
+}
+
+impl ValveSearch {
+    // `distances` has a row and a column for every valve plus the start
+    pub fn new(rates: Vec<usize>, distances: Vec<Vec<usize>>) -> Self {
+        let mut by_rate = (0..rates.len()).collect::<Vec<_>>();
+        by_rate.sort_by_key(|valve| std::cmp::Reverse(rates[*valve]));
+        Self {
+            rates,
+            distances,
+            by_rate,
+        }
+    }
+
+    fn start(&self) -> usize {
+        self.rates.len()
+    }
+
+    fn subsets(&self) -> usize {
+        1 << self.rates.len()
+    }
+
+    // The valves that can still be opened with a minute of flow left, and the
+    // minutes remaining once they are
+    fn moves(&self, (position, opened, remaining): State) -> impl Iterator<Item = State> + '_ {
+        self.by_rate.iter().filter_map(move |&valve| {
+            let cost = self.distances[position][valve] + 1;
+            (opened & (1 << valve) == 0 && cost < remaining)
+                .then(|| (valve, opened | (1 << valve), remaining - cost))
+        })
+    }
+
+    // Assumes the nearest closed valve is opened first and every other one
+    // only two minutes after the previous, best rates first
+    fn upper_bound(&self, (position, opened, remaining): State) -> usize {
+        let mut closed = self
+            .by_rate
+            .iter()
+            .filter(|valve| opened & (1 << **valve) == 0)
+            .peekable();
+        let Some(nearest) = closed
+            .clone()
+            .map(|valve| self.distances[position][*valve])
+            .min()
+        else {
+            return 0;
+        };
+
+        let mut time = remaining.saturating_sub(nearest + 1);
+        let mut bound = 0;
+        while let (Some(valve), true) = (closed.next(), time > 0) {
+            bound += self.rates[*valve] * time;
+            time = time.saturating_sub(2);
+        }
+        bound
+    }
+
+    /// The most pressure a single actor can release in `minutes`
+    pub fn best(&self, minutes: usize) -> usize {
+        self.plan(minutes, self.subsets() - 1).released
+    }
+
+    /// The best order for a single actor to open valves in, using only the
+    /// valves in `allowed`
+    pub fn plan(&self, minutes: usize, allowed: usize) -> Plan {
+        let mut best = Plan::default();
+        let mut route = vec![];
+        let mut seen = HashMap::new();
+        // the valves that aren't allowed are as good as opened already
+        let opened = (self.subsets() - 1) & !allowed;
+        self.branch(
+            (self.start(), opened, minutes),
+            0,
+            &mut route,
+            &mut best,
+            &mut seen,
+        );
+        best
+    }
+
+    fn branch(
+        &self,
+        state: State,
+        released: usize,
+        route: &mut Vec<usize>,
+        best: &mut Plan,
+        seen: &mut HashMap<State, usize>,
+    ) {
+        if released > best.released {
+            *best = Plan {
+                released,
+                valves: route.clone(),
+            };
+        }
+        if released + self.upper_bound(state) <= best.released {
+            return;
+        }
+        // getting here again with less pressure released can't do any better
+        if seen.get(&state).is_some_and(|before| *before >= released) {
+            return;
+        }
+        seen.insert(state, released);
+
+        for next in self.moves(state) {
+            let (valve, _, remaining) = next;
+            route.push(valve);
+            self.branch(
+                next,
+                released + self.rates[valve] * remaining,
+                route,
+                best,
+                seen,
+            );
+            route.pop();
+        }
+    }
+
+    /// The most pressure a single actor can release in `minutes` by opening
+    /// exactly each set of valves
+    pub fn best_per_subset(&self, minutes: usize) -> Vec<usize> {
+        let mut best = vec![0; self.subsets()];
+        let mut seen = HashMap::new();
+        self.visit((self.start(), 0, minutes), 0, &mut best, &mut seen);
+        best
+    }
+
+    // Every set of valves needs its own best, so there is no single total to
+    // prune against. Instead `seen` keeps the (minutes remaining, released)
+    // pairs each position and set of opened valves was reached with: getting
+    // there again later with no more released can only lead to worse entries.
+    fn visit(
+        &self,
+        state: State,
+        released: usize,
+        best: &mut [usize],
+        seen: &mut HashMap<(usize, usize), Vec<(usize, usize)>>,
+    ) {
+        let (position, opened, remaining) = state;
+        let reached = seen.entry((position, opened)).or_default();
+        if reached
+            .iter()
+            .any(|(before, most)| *before >= remaining && *most >= released)
+        {
+            return;
+        }
+        reached.push((remaining, released));
+        best[opened] = best[opened].max(released);
+
+        for next in self.moves(state) {
+            let (valve, _, remaining) = next;
+            self.visit(next, released + self.rates[valve] * remaining, best, seen);
+        }
+    }
+}
+
+/// The most pressure `actors` can release together, each opening a different
+/// set of valves, given the best a single actor can do with every set
+pub fn combine(best: Vec<usize>, actors: usize) -> usize {
+    split(best, actors).0
+}
+
+/// Same as `combine`, also returning the set of valves each actor should
+/// open. Each actor can do its share alone in the time with `plan`.
+pub fn split(mut best: Vec<usize>, actors: usize) -> (usize, Vec<usize>) {
+    // make every entry the best for *at most* that set of valves, so actors
+    // don't have to split the valves exactly
+    let subsets = best.len();
+    for bit in 0..subsets.trailing_zeros() {
+        for opened in 0..subsets {
+            if opened & (1 << bit) != 0 {
+                best[opened] = best[opened].max(best[opened ^ (1 << bit)]);
+            }
+        }
+    }
+
+    let all = subsets - 1;
+    if actors <= 1 {
+        return (best[all] * actors, vec![all; actors]);
+    }
+
+    // the best share for one more actor, and what it adds to the others
+    let join = |team: &[usize], available: usize| {
+        submasks(available)
+            .map(|mine| (best[mine] + team[available ^ mine], mine))
+            .max()
+            .unwrap_or((0, 0))
+    };
+
+    // teams[k] is the best k + 1 actors can do with every set of valves. Only
+    // the last actor needs the full set, so it never gets a table.
+    let mut teams = vec![best.clone()];
+    for _ in 2..actors {
+        let team = (0..subsets)
+            .map(|available| join(teams.last().unwrap(), available).0)
+            .collect();
+        teams.push(team);
+    }
+
+    let released = join(teams.last().unwrap(), all).0;
+    let mut shares = vec![];
+    let mut available = all;
+    for team in teams.iter().rev() {
+        let (_, mine) = join(team, available);
+        shares.push(mine);
+        available ^= mine;
+    }
+    shares.push(available);
+
+    (released, shares)
+}
+
+// every subset of `mask`, including the empty one and `mask` itself
+fn submasks(mask: usize) -> impl Iterator<Item = usize> {
+    let mut next = Some(mask);
+    std::iter::from_fn(move || {
+        let current = next?;
+        next = (current != 0).then(|| (current - 1) & mask);
+        Some(current)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // start -1- A(10) -1- B(5), with C(20) three steps away from the start
+    fn search() -> ValveSearch {
+        ValveSearch::new(
+            vec![10, 5, 20],
+            vec![
+                vec![0, 1, 4, 1],
+                vec![1, 0, 5, 2],
+                vec![4, 5, 0, 3],
+                vec![1, 2, 3, 0],
+            ],
+        )
+    }
+
+    #[test]
+    fn test_best() {
+        let search = search();
+        // A with 8 minutes left, then C with 3: 10 * 8 + 20 * 3
+        assert_eq!(search.best(10), 140);
+        assert_eq!(search.best(1), 0);
+    }
+
+    #[test]
+    fn test_best_per_subset() {
+        let search = search();
+        let best = search.best_per_subset(10);
+
+        assert_eq!(best[0b001], 80);
+        assert_eq!(best[0b011], 80 + 30);
+        assert_eq!(best[0b100], 120);
+        assert_eq!(best.iter().max(), Some(&search.best(10)));
+    }
+
+    #[test]
+    fn test_upper_bound() {
+        let search = search();
+        // 20 * 8 + 10 * 6 + 5 * 4, as if every valve was one step apart
+        assert_eq!(search.upper_bound((3, 0, 10)), 240);
+        assert_eq!(search.upper_bound((3, 0b111, 10)), 0);
+        assert!(search.upper_bound((3, 0, 10)) >= search.best(10));
+    }
+
+    #[test]
+    fn test_combine() {
+        let search = search();
+        let best = search.best_per_subset(10);
+        // one actor takes C, the other A then B
+        assert_eq!(combine(best.clone(), 2), 120 + 110);
+        assert_eq!(combine(best.clone(), 1), 140);
+        assert_eq!(combine(best.clone(), 0), 0);
+    }
+
+    #[test]
+    fn test_plan() {
+        let search = search();
+        assert_eq!(
+            search.plan(10, 0b111),
+            Plan {
+                released: 140,
+                valves: vec![0, 2]
+            }
+        );
+        // without A, C first and then nothing else fits
+        assert_eq!(search.plan(10, 0b110).valves, vec![2]);
+        assert_eq!(search.plan(10, 0), Plan::default());
+    }
+
+    #[test]
+    fn test_split() {
+        let search = search();
+        let best = search.best_per_subset(10);
+
+        let (released, shares) = split(best.clone(), 2);
+        assert_eq!(released, 230);
+        assert_eq!(shares.iter().fold(0, |all, share| all | share), 0b111);
+        assert_eq!(
+            shares
+                .iter()
+                .map(|share| search.plan(10, *share).released)
+                .sum::<usize>(),
+            released
+        );
+
+        let (released, shares) = split(best, 3);
+        assert_eq!(released, 120 + 80 + 35);
+        assert_eq!(shares.len(), 3);
+    }
+
+    #[test]
+    fn test_submasks() {
+        let mut subsets = submasks(0b101).collect::<Vec<_>>();
+        subsets.sort();
+        assert_eq!(subsets, vec![0b000, 0b001, 0b100, 0b101]);
+        assert_eq!(submasks(0).collect::<Vec<_>>(), vec![0]);
+    }
+}